@@ -0,0 +1,226 @@
+use crate::error::{Error, ErrorKind};
+use crate::paths::Paths;
+use crate::reporters::report_format::ReportFormat;
+
+pub const USAGE: &str = "Usage: cooplan-definitions-validator [COMMAND] [OPTIONS]
+
+Commands:
+    validate        Generates the missing ids, writes them and validates all categories (default).
+    generate-ids    Generates the missing ids and writes them, without running any validation.
+    check           Validates all categories without writing any file, failing if any file would change.
+    help            Prints this message.
+
+Options:
+    --config <PATH>             Configuration file. Default: ./config.json
+    --categories <PATH>         Root directory of the categories. Default: ./categories/
+    --category-tracker <PATH>   Category id tracker file. Default: ./category_id_tracker.csv
+    --attribute-tracker <PATH>  Attribute id tracker file. Default: ./attribute_id_tracker.csv
+    --option-tracker <PATH>     Option id tracker file. Default: ./option_id_tracker.csv
+    --format <FORMAT>           Format of the report: text, json or sarif. Default: text
+    --output <PATH>             File the report is written to. Default: standard output
+    --sarif-base <PATH>         Directory the SARIF report's locations are relative to, e.g. the repository's
+                                root. Default: current directory
+    --deny-warnings             Fails if any warning is found.
+    --fix-layout                Moves the category files that are not where their parents place them.
+                                Not available when checking.
+
+Exit codes:
+    0    No problem found, or only warnings that are not denied.
+    1    Errors found, invalid definitions, or files would change when checking.
+    2    Only warnings found, while warnings are denied.
+    3    The validations could not be run, e.g. invalid arguments, unreadable configuration or files.";
+
+const DEFAULT_SARIF_BASE: &str = ".";
+
+/// Action requested through the command line.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Command {
+    Validate,
+    GenerateIds,
+    Check,
+    Help,
+}
+
+impl Command {
+    fn try_from_name(name: &str) -> Option<Command> {
+        match name {
+            "validate" => Some(Command::Validate),
+            "generate-ids" => Some(Command::GenerateIds),
+            "check" => Some(Command::Check),
+            "help" | "--help" | "-h" => Some(Command::Help),
+            _ => None,
+        }
+    }
+}
+
+/// Parsed command line arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arguments {
+    command: Command,
+    paths: Paths,
+    format: ReportFormat,
+    output: Option<String>,
+    sarif_base: String,
+    deny_warnings: bool,
+    fix_layout: bool,
+}
+
+impl Arguments {
+    /// Parses the command line arguments, without the program's name.
+    ///
+    /// Options accept their value either as the next argument (`--config ./config.json`) or
+    /// joined by '=' (`--config=./config.json`). If no command is specified, `validate` is assumed.
+    ///
+    /// Error kinds:
+    ///
+    /// * `InvalidArgument` - if an argument is unknown, repeated or lacks its value, or if the layout is
+    ///   to be fixed while checking.
+    pub fn parse(args: &[String]) -> Result<Arguments, Error> {
+        let mut command: Option<Command> = None;
+        let mut paths = Paths::default();
+        let mut format = ReportFormat::Text;
+        let mut output: Option<String> = None;
+        let mut sarif_base = DEFAULT_SARIF_BASE.to_string();
+        let mut deny_warnings = false;
+        let mut fix_layout = false;
+
+        let mut index = 0;
+        while index < args.len() {
+            let arg = args[index].as_str();
+            index += 1;
+
+            if let Some(parsed_command) = Command::try_from_name(arg) {
+                if parsed_command == Command::Help {
+                    command = Some(Command::Help);
+                    continue;
+                }
+
+                match command {
+                    Some(Command::Help) => (),
+                    Some(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidArgument,
+                            format!("unexpected second command '{}'", arg).as_str(),
+                        ))
+                    }
+                    None => command = Some(parsed_command),
+                }
+
+                continue;
+            }
+
+            if !arg.starts_with("--") {
+                return Err(Error::new(
+                    ErrorKind::InvalidArgument,
+                    format!("unknown command '{}'", arg).as_str(),
+                ));
+            }
+
+            if arg == "--deny-warnings" {
+                deny_warnings = true;
+                continue;
+            }
+
+            if arg == "--fix-layout" {
+                fix_layout = true;
+                continue;
+            }
+
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name, value.to_string()),
+                None => match args.get(index) {
+                    Some(value) if !value.starts_with("--") => {
+                        index += 1;
+                        (arg, value.clone())
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidArgument,
+                            format!("option '{}' requires a value", arg).as_str(),
+                        ))
+                    }
+                },
+            };
+
+            if value.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidArgument,
+                    format!("option '{}' requires a non-empty value", name).as_str(),
+                ));
+            }
+
+            match name {
+                "--config" => paths.set_config(value.as_str()),
+                "--categories" => paths.set_categories_root(value.as_str()),
+                "--category-tracker" => paths.set_category_id_tracker(value.as_str()),
+                "--attribute-tracker" => paths.set_attribute_id_tracker(value.as_str()),
+                "--option-tracker" => paths.set_option_id_tracker(value.as_str()),
+                "--format" => match ReportFormat::try_from_name(value.as_str()) {
+                    Some(report_format) => format = report_format,
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidArgument,
+                            format!("unknown report format '{}'", value).as_str(),
+                        ))
+                    }
+                },
+                "--output" => output = Some(value),
+                "--sarif-base" => sarif_base = value,
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidArgument,
+                        format!("unknown option '{}'", name).as_str(),
+                    ))
+                }
+            }
+        }
+
+        let command = command.unwrap_or(Command::Validate);
+
+        if fix_layout && command == Command::Check {
+            return Err(Error::new(
+                ErrorKind::InvalidArgument,
+                "option '--fix-layout' cannot be used with 'check', given it does not write any file",
+            ));
+        }
+
+        Ok(Arguments {
+            command,
+            paths,
+            format,
+            output,
+            sarif_base,
+            deny_warnings,
+            fix_layout,
+        })
+    }
+
+    pub fn command(&self) -> Command {
+        self.command
+    }
+
+    pub fn paths(&self) -> &Paths {
+        &self.paths
+    }
+
+    pub fn format(&self) -> ReportFormat {
+        self.format
+    }
+
+    pub fn output(&self) -> Option<&str> {
+        self.output.as_deref()
+    }
+
+    /// Directory the SARIF report's locations are relative to.
+    pub fn sarif_base(&self) -> &str {
+        self.sarif_base.as_str()
+    }
+
+    pub fn deny_warnings(&self) -> bool {
+        self.deny_warnings
+    }
+
+    pub fn fix_layout(&self) -> bool {
+        self.fix_layout
+    }
+}
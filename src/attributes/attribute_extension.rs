@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::constraints::Constraints;
+
+/// Option of an enumerated attribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeOption {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+}
+
+/// Fields of an attribute's definition that the definitions library does not know about, therefore they
+/// are read and written by the validator itself.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttributeExtension {
+    /// Options of the attribute, if its data type is `enum`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<AttributeOption>,
+    #[serde(flatten)]
+    pub constraints: Constraints,
+    /// Value the attribute takes when none is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+impl AttributeExtension {
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty() && self.constraints.is_empty() && self.default.is_none()
+    }
+}
+
+#[derive(Deserialize)]
+struct CategoryExtension {
+    #[serde(default)]
+    attributes: Vec<AttributeExtension>,
+}
+
+/// Reads the extension of each attribute within the category's file, in the same order as the attributes.
+pub fn read_attribute_extensions(path: &str) -> Result<Vec<AttributeExtension>, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => match serde_json::from_str::<CategoryExtension>(content.as_str()) {
+            Ok(category_extension) => Ok(category_extension.attributes),
+            Err(error) => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("[{}] {}", path, error),
+            )),
+        },
+        Err(error) => Err(error),
+    }
+}
+
+/// Adds the attributes' extensions, with the attribute's id as key, into the category's file, which has
+/// been written without them.
+pub fn write_attribute_extensions(
+    path: &str,
+    extensions: &HashMap<String, AttributeExtension>,
+) -> Result<(), Error> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => match merge_attribute_extensions(content.as_str(), extensions) {
+            Ok(content) => std::fs::write(path, content),
+            Err(error) => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("[{}] {}", path, error),
+            )),
+        },
+        Err(error) => Err(error),
+    }
+}
+
+/// Adds the attributes' extensions, with the attribute's id as key, into the category's definition, returning
+/// the definition as it would be written.
+pub fn merge_attribute_extensions(
+    content: &str,
+    extensions: &HashMap<String, AttributeExtension>,
+) -> Result<String, serde_json::Error> {
+    match serde_json::from_str::<Value>(content) {
+        Ok(mut category) => {
+            if let Some(attributes) = category
+                .get_mut("attributes")
+                .and_then(|attributes| attributes.as_array_mut())
+            {
+                for attribute in attributes {
+                    let extension = match attribute
+                        .get("id")
+                        .and_then(|id| id.as_str())
+                        .and_then(|id| extensions.get(id))
+                    {
+                        Some(extension) => extension,
+                        None => continue,
+                    };
+
+                    if let (Some(attribute), Ok(Value::Object(fields))) =
+                        (attribute.as_object_mut(), serde_json::to_value(extension))
+                    {
+                        attribute.extend(fields);
+                    }
+                }
+            }
+
+            serde_json::to_string_pretty(&category)
+        }
+        Err(error) => Err(error),
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::error::{Error, ErrorKind};
+use crate::error::{Error, ErrorDetails, ErrorKind};
 
 use super::attribute_tracker_io::AttributeEntry;
 
@@ -42,13 +42,15 @@ impl AttributeIdTracker {
                     return Err(Error::new(
                         ErrorKind::DuplicatedId,
                         format!("duplicated attribute id {}", id).as_str(),
-                    ));
+                    )
+                    .with_details(ErrorDetails::Id { id: id.to_string() }));
                 }
 
                 Err(Error::new(
                     ErrorKind::IdNotFound,
                     format!("attribute with id {} does not exist", id).as_str(),
-                ))
+                )
+                .with_details(ErrorDetails::Id { id: id.to_string() }))
             }
         }
     }
@@ -63,17 +65,17 @@ impl AttributeIdTracker {
             return Ok(());
         }
 
-        let mut missing_ids: String = "".to_string();
-
-        for (id, _) in self.entries.iter() {
-            missing_ids.push_str(&format!("{}, ", id));
-        }
-
-        missing_ids = missing_ids.trim_end_matches(", ").to_string();
+        let mut missing_ids: Vec<String> = self.entries.keys().cloned().collect();
+        missing_ids.sort();
 
         Err(Error::new(
             ErrorKind::IdNotTracked,
-            format!("some attribute ids were not tracked: {}", missing_ids).as_str(),
-        ))
+            format!(
+                "some attribute ids were not tracked: {}",
+                missing_ids.join(", ")
+            )
+            .as_str(),
+        )
+        .with_details(ErrorDetails::Ids { ids: missing_ids }))
     }
 }
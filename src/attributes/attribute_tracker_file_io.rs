@@ -1,56 +1,205 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io::{Error, ErrorKind},
 };
 
 use crate::attributes::attribute_tracker_io::AttributeTrackerIO;
 
 use super::attribute_tracker_io::AttributeEntry;
+use super::constraints::Constraints;
 
-pub struct AttributeTrackerFileIO {}
-
-const ID_TRACKER_PATH: &str = "./attribute_id_tracker.csv";
+pub struct AttributeTrackerFileIO {
+    path: String,
+}
 
 impl AttributeTrackerFileIO {
-    pub fn new() -> AttributeTrackerFileIO {
-        AttributeTrackerFileIO {}
+    pub fn new(path: &str) -> AttributeTrackerFileIO {
+        AttributeTrackerFileIO {
+            path: path.to_string(),
+        }
+    }
+}
+
+const SEPARATOR: char = ';';
+const ESCAPE: char = '\\';
+
+/// Escapes the separator and the escape character itself within a field, i.e. "salt;pepper" is written
+/// as "salt\;pepper". Line breaks are written as "\n" and "\r", so every entry stays within its line.
+pub fn escape_field(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+
+    for character in field.chars() {
+        match character {
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            SEPARATOR | ESCAPE => {
+                escaped.push(ESCAPE);
+                escaped.push(character);
+            }
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Splits a line into, at most, `max_fields` fields, unescaping all but the last one, which is kept
+/// as written.
+pub fn split_fields(line: &str, max_fields: usize) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut characters = line.char_indices();
+
+    while let Some((index, character)) = characters.next() {
+        if fields.len() + 1 == max_fields {
+            field.push_str(&line[index..]);
+            break;
+        }
+
+        match character {
+            ESCAPE => match characters.next() {
+                Some((_, 'n')) => field.push('\n'),
+                Some((_, 'r')) => field.push('\r'),
+                Some((_, escaped)) => field.push(escaped),
+                None => field.push(ESCAPE),
+            },
+            SEPARATOR => fields.push(std::mem::take(&mut field)),
+            _ => field.push(character),
+        }
+    }
+
+    fields.push(field);
+    fields
+}
+
+/// Line of the file, i.e. "1234-1234-1234;decimal;kg;weight;5678-5678-5678;false;{"min":0}", where every
+/// field but the constraints is escaped.
+fn format_entry(entry: &AttributeEntry) -> String {
+    let constraints = match &entry.constraints {
+        Some(constraints) => serde_json::to_string(constraints).unwrap_or_default(),
+        None => String::new(),
+    };
+    let optional = match entry.optional {
+        Some(optional) => optional.to_string(),
+        None => String::new(),
+    };
+
+    format!(
+        "{};{};{};{};{};{};{}",
+        escape_field(entry.id.as_str()),
+        escape_field(entry.data_type.as_str()),
+        escape_field(entry.unit.as_deref().unwrap_or_default()),
+        escape_field(entry.name.as_deref().unwrap_or_default()),
+        escape_field(entry.category_id.as_deref().unwrap_or_default()),
+        optional,
+        constraints
+    )
+}
+
+fn parse_optional(field: &str) -> Result<Option<bool>, Error> {
+    match field {
+        "" => Ok(None),
+        "true" => Ok(Some(true)),
+        "false" => Ok(Some(false)),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Attribute id tracker file contains a malformed optional field: {}",
+                field
+            ),
+        )),
+    }
+}
+
+fn parse_constraints(column: &str) -> Result<Option<Constraints>, Error> {
+    if column.is_empty() {
+        return Ok(None);
+    }
+
+    match serde_json::from_str(column) {
+        Ok(constraints) => Ok(Some(constraints)),
+        Err(error) => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Attribute id tracker file contains malformed constraints: {}",
+                error
+            ),
+        )),
+    }
+}
+
+/// Reads a line with every field, i.e. "1234-1234-1234;decimal;kg;weight;5678-5678-5678;false;{"min":0}".
+fn parse_entry(fields: &[String]) -> Result<AttributeEntry, Error> {
+    match parse_optional(fields[5].as_str()) {
+        Ok(optional) => match parse_constraints(fields[6].as_str()) {
+            Ok(constraints) => Ok(AttributeEntry {
+                id: fields[0].to_string(),
+                data_type: fields[1].to_string(),
+                unit: Some(fields[2].to_string()),
+                name: Some(fields[3].to_string()),
+                category_id: Some(fields[4].to_string()),
+                constraints,
+                optional,
+            }),
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
     }
 }
 
 impl AttributeTrackerIO for AttributeTrackerFileIO {
-    /// Proceeds to read the entries from a file that contains the id and the data type of an attribute, separated by ';', i.e. "1234-1234-1234;date".
+    /// Proceeds to read the entries from a file that contains the id, the data type, the unit, the name, the
+    /// category id, whether it is optional and the constraints, as JSON, of an attribute, separated by ';', i.e.
+    /// "1234-1234-1234;decimal;kg;weight;5678-5678-5678;false;{"min":0}". The unit is empty if the attribute
+    /// has none.
+    ///
+    /// Within every field but the constraints, ';' and '\' are escaped with a '\', i.e. "salt\;pepper", and line
+    /// breaks are written as "\n" and "\r".
+    ///
+    /// Lines that predate the tracking of the other fields, i.e. "1234-1234-1234;date", only contain the id and
+    /// the data type, leaving the other fields as `None` until the entry is migrated. Lines with any other number
+    /// of fields are malformed.
     ///
     /// # Returns
     ///
     /// `Ok`: `HashMap` where the keys, are the id of the attribute, and the value are instances of `AttributeEntry`.
     /// `Err`: error detailing why the function has failed.
     fn read_entries(&self) -> Result<HashMap<String, AttributeEntry>, std::io::Error> {
-        const SPLIT_PATTERN: &str = ";";
         let mut entries: HashMap<String, AttributeEntry> = HashMap::new();
 
-        match std::fs::read_to_string(ID_TRACKER_PATH) {
+        match std::fs::read_to_string(self.path.as_str()) {
             Ok(content) => {
                 for line in content.lines() {
                     if line.is_empty() {
                         continue;
                     }
 
-                    let result: Vec<&str> = line.split(SPLIT_PATTERN).collect();
+                    // The constraints, which may contain the separator, are read as written.
+                    let result: Vec<String> = split_fields(line, 7);
 
-                    if result.len() <= 0 || result.len() > 2 {
-                        return Err(Error::new(
+                    let entry = match result.len() {
+                        2 => Ok(AttributeEntry {
+                            id: result[0].to_string(),
+                            data_type: result[1].to_string(),
+                            unit: None,
+                            name: None,
+                            category_id: None,
+                            constraints: None,
+                            optional: None,
+                        }),
+                        7 => parse_entry(&result),
+                        _ => Err(Error::new(
                             ErrorKind::InvalidData,
                             "Attribute id tracker file contains a malformed line.",
-                        ));
-                    }
+                        )),
+                    };
 
-                    entries.insert(
-                        result[0].to_string(),
-                        AttributeEntry {
-                            id: result[0].to_string(),
-                            data_type: result[1].to_string(),
-                        },
-                    );
+                    match entry {
+                        Ok(entry) => {
+                            entries.insert(entry.id.clone(), entry);
+                        }
+                        Err(error) => return Err(error),
+                    }
                 }
             }
             Err(error) => {
@@ -62,11 +211,11 @@ impl AttributeTrackerIO for AttributeTrackerFileIO {
     }
 
     fn write_entry(&self, entry: &AttributeEntry) -> Result<(), std::io::Error> {
-        match std::fs::read_to_string(ID_TRACKER_PATH) {
+        match std::fs::read_to_string(self.path.as_str()) {
             Ok(current_content) => {
-                let new_content = format!("{}\n{};{}", current_content, entry.id, entry.data_type);
+                let new_content = format!("{}\n{}", current_content, format_entry(entry));
 
-                match std::fs::write(ID_TRACKER_PATH, new_content) {
+                match std::fs::write(self.path.as_str(), new_content) {
                     Ok(_) => Ok(()),
                     Err(error) => Err(error),
                 }
@@ -74,4 +223,47 @@ impl AttributeTrackerIO for AttributeTrackerFileIO {
             Err(error) => Err(error),
         }
     }
+
+    /// Rewrites the file with the entries, so every line has every field. Entries keep the line they were
+    /// written in, and the entries that were not within the file are appended, sorted by their id.
+    fn write_entries(
+        &self,
+        entries: &HashMap<String, AttributeEntry>,
+    ) -> Result<(), std::io::Error> {
+        let current_content = match std::fs::read_to_string(self.path.as_str()) {
+            Ok(current_content) => current_content,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let mut written_ids: Vec<String> = Vec::new();
+        let mut known_ids: HashSet<String> = HashSet::new();
+
+        for line in current_content.lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let id = split_fields(line, 2).remove(0);
+
+            if entries.contains_key(&id) && known_ids.insert(id.clone()) {
+                written_ids.push(id);
+            }
+        }
+
+        let mut new_entries: Vec<&AttributeEntry> = entries
+            .values()
+            .filter(|entry| !known_ids.contains(&entry.id))
+            .collect();
+        new_entries.sort_by(|first, second| first.id.cmp(&second.id));
+
+        let lines: Vec<String> = written_ids
+            .iter()
+            .filter_map(|id| entries.get(id))
+            .chain(new_entries)
+            .map(format_entry)
+            .collect();
+
+        std::fs::write(self.path.as_str(), lines.join("\n"))
+    }
 }
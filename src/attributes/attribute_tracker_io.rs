@@ -1,12 +1,211 @@
 use std::{collections::HashMap, io::Error};
 
+use cooplan_definitions_lib::attribute::Attribute;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::constraints::Constraints;
+
+/// Tracked state of an attribute. Fields are `None` if the entry predates their tracking, until the
+/// entry is migrated.
 #[derive(Clone)]
 pub struct AttributeEntry {
     pub id: String,
     pub data_type: String,
+    /// Unit of the attribute, empty if it has none.
+    pub unit: Option<String>,
+    pub name: Option<String>,
+    /// Id of the category the attribute belongs to.
+    pub category_id: Option<String>,
+    pub constraints: Option<Constraints>,
+    /// Whether the attribute is optional, tracked alongside its constraints.
+    pub optional: Option<bool>,
+}
+
+impl AttributeEntry {
+    /// Fills the fields that predate their tracking with the attribute's current state.
+    ///
+    /// Returns whether any field has been filled.
+    pub fn migrate(
+        &mut self,
+        category_id: &str,
+        attribute: &Attribute,
+        constraints: &Constraints,
+    ) -> bool {
+        let mut migrated = false;
+
+        if self.unit.is_none() {
+            self.unit = Some(attribute.unit.clone().unwrap_or_default());
+            migrated = true;
+        }
+
+        if self.name.is_none() {
+            self.name = Some(attribute.name.clone());
+            migrated = true;
+        }
+
+        if self.category_id.is_none() {
+            self.category_id = Some(category_id.to_string());
+            migrated = true;
+        }
+
+        if self.constraints.is_none() {
+            self.constraints = Some(constraints.clone());
+            migrated = true;
+        }
+
+        if self.optional.is_none() {
+            self.optional = Some(attribute.optional);
+            migrated = true;
+        }
+
+        migrated
+    }
+
+    /// Tracks the attribute's current constraints and whether it is optional, if they have only been loosened.
+    ///
+    /// Returns whether the tracked constraints have changed.
+    pub fn accept_loosened_constraints(
+        &mut self,
+        constraints: &Constraints,
+        optional: bool,
+    ) -> bool {
+        let tracked_optional = self.optional.unwrap_or(optional);
+
+        match &self.constraints {
+            Some(tracked)
+                if (tracked != constraints || tracked_optional != optional)
+                    && constraints
+                        .tightenings(optional, tracked, tracked_optional)
+                        .is_empty() =>
+            {
+                self.constraints = Some(constraints.clone());
+                self.optional = Some(optional);
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Tracks the attribute's current state of the field, whose change has been allowed.
+    ///
+    /// Returns whether the tracked state has changed.
+    pub fn accept_change(
+        &mut self,
+        field: TrackedField,
+        category_id: &str,
+        attribute: &Attribute,
+        constraints: &Constraints,
+    ) -> bool {
+        let (tracked, current) = match field {
+            TrackedField::Name => (&mut self.name, attribute.name.clone()),
+            TrackedField::DataType => {
+                if self.data_type == attribute.data_type {
+                    return false;
+                }
+
+                self.data_type = attribute.data_type.clone();
+
+                return true;
+            }
+            TrackedField::Unit => (&mut self.unit, attribute.unit.clone().unwrap_or_default()),
+            TrackedField::Category => (&mut self.category_id, category_id.to_string()),
+            TrackedField::Constraints => {
+                if self.constraints.as_ref() == Some(constraints)
+                    && self.optional == Some(attribute.optional)
+                {
+                    return false;
+                }
+
+                self.constraints = Some(constraints.clone());
+                self.optional = Some(attribute.optional);
+
+                return true;
+            }
+        };
+
+        if tracked.as_deref() == Some(current.as_str()) {
+            return false;
+        }
+
+        *tracked = Some(current);
+
+        true
+    }
+}
+
+/// Field of an attribute's entry, whose changes can be explicitly allowed.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackedField {
+    Name,
+    DataType,
+    Unit,
+    Category,
+    Constraints,
+}
+
+/// Change of a tracked field of an attribute, allowed only while the field changes into the expected value,
+/// so the allowance stops matching once it has been applied and the field changes again:
+///
+/// ```json
+/// { "field": "data_type", "to": "decimal" }
+/// ```
+///
+/// `to` is the attribute's name, data type, unit (`null` or empty if it has none) or category id, or, for
+/// `constraints`, its constraints alongside whether it is optional, i.e. `{ "optional": true, "min": 0 }`,
+/// where `optional` defaults to `false`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AllowedAttributeChange {
+    pub field: TrackedField,
+    pub to: Value,
+}
+
+impl AllowedAttributeChange {
+    pub fn new(field: TrackedField, to: Value) -> AllowedAttributeChange {
+        AllowedAttributeChange { field, to }
+    }
+
+    /// Whether the attribute's current state of the field is the one the change is allowed into.
+    pub fn matches(
+        &self,
+        category_id: &str,
+        attribute: &Attribute,
+        constraints: &Constraints,
+    ) -> bool {
+        match self.field {
+            TrackedField::Name => self.to.as_str() == Some(attribute.name.as_str()),
+            TrackedField::DataType => self.to.as_str() == Some(attribute.data_type.as_str()),
+            TrackedField::Unit => {
+                let unit = attribute.unit.as_deref().unwrap_or_default();
+
+                match &self.to {
+                    Value::Null => unit.is_empty(),
+                    Value::String(to) => to == unit,
+                    _ => false,
+                }
+            }
+            TrackedField::Category => self.to.as_str() == Some(category_id),
+            TrackedField::Constraints => {
+                let optional = match self.to.get("optional") {
+                    Some(optional) => optional.as_bool(),
+                    None => Some(false),
+                };
+
+                optional == Some(attribute.optional)
+                    && serde_json::from_value::<Constraints>(self.to.clone())
+                        .ok()
+                        .as_ref()
+                        == Some(constraints)
+            }
+        }
+    }
 }
 
 pub trait AttributeTrackerIO {
     fn read_entries(&self) -> Result<HashMap<String, AttributeEntry>, Error>;
     fn write_entry(&self, entry: &AttributeEntry) -> Result<(), Error>;
+    /// Replaces every entry, e.g. for migrating or updating them.
+    fn write_entries(&self, entries: &HashMap<String, AttributeEntry>) -> Result<(), Error>;
 }
@@ -0,0 +1,38 @@
+use std::{cell::RefCell, collections::HashMap, io::Error};
+
+use crate::attributes::attribute_tracker_io::AttributeTrackerIO;
+
+use super::attribute_tracker_io::AttributeEntry;
+
+/// In-memory attribute tracking storage, used for running the CI logic without writing any file.
+pub struct AttributeTrackerMemoryIO {
+    entries: RefCell<HashMap<String, AttributeEntry>>,
+}
+
+impl AttributeTrackerMemoryIO {
+    pub fn new(entries: HashMap<String, AttributeEntry>) -> AttributeTrackerMemoryIO {
+        AttributeTrackerMemoryIO {
+            entries: RefCell::new(entries),
+        }
+    }
+}
+
+impl AttributeTrackerIO for AttributeTrackerMemoryIO {
+    fn read_entries(&self) -> Result<HashMap<String, AttributeEntry>, Error> {
+        Ok(self.entries.borrow().clone())
+    }
+
+    fn write_entry(&self, entry: &AttributeEntry) -> Result<(), Error> {
+        self.entries
+            .borrow_mut()
+            .insert(entry.id.clone(), entry.clone());
+
+        Ok(())
+    }
+
+    fn write_entries(&self, entries: &HashMap<String, AttributeEntry>) -> Result<(), Error> {
+        self.entries.replace(entries.clone());
+
+        Ok(())
+    }
+}
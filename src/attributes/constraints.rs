@@ -0,0 +1,237 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+
+use crate::type_system::{DataType, LIST, NUMERIC_DATA_TYPES};
+
+const SIZED_DATA_TYPES: [&str; 2] = ["string", LIST];
+const TEXTUAL_DATA_TYPES: [&str; 1] = ["string"];
+
+/// Constraints on the values of an attribute, declared alongside it. Every constraint is optional:
+///
+/// ```json
+/// {
+///     "min": 0,
+///     "max": 100,
+///     "min_length": 1,
+///     "max_length": 64,
+///     "pattern": "^[A-Z]"
+/// }
+/// ```
+///
+/// * `min`, `max` - inclusive range of the value, for `integer` and `decimal`.
+/// * `min_length`, `max_length` - inclusive range of the length, for `string` and `list`.
+/// * `pattern` - regular expression the value must match, for `string`.
+///
+/// Whether a value must be set is the attribute's own `optional` field, which is tracked alongside its
+/// constraints.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Constraints {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+/// Problem with one of the constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstraintProblem {
+    pub constraint: &'static str,
+    pub message: String,
+}
+
+impl ConstraintProblem {
+    fn new(constraint: &'static str, message: String) -> ConstraintProblem {
+        ConstraintProblem {
+            constraint,
+            message,
+        }
+    }
+}
+
+impl Constraints {
+    pub fn is_empty(&self) -> bool {
+        self == &Constraints::default()
+    }
+
+    /// Problems of the constraints, given the attribute's data type, e.g. a `pattern` for a `decimal`, or a
+    /// `min` greater than the `max`.
+    pub fn violations(&self, data_type: &str) -> Vec<ConstraintProblem> {
+        let mut violations: Vec<ConstraintProblem> = Vec::new();
+        let parsed_data_type = DataType::parse(data_type).ok();
+        let data_type_name = match &parsed_data_type {
+            Some(parsed_data_type) => parsed_data_type.name().to_string(),
+            None => data_type.to_string(),
+        };
+
+        let mut check_data_type = |constraint: &'static str, is_set: bool, legal: &[&str]| {
+            if is_set && !legal.contains(&data_type_name.as_str()) {
+                violations.push(ConstraintProblem::new(
+                    constraint,
+                    format!(
+                        "'{}' cannot be set for the data type '{}', only for: {}",
+                        constraint,
+                        data_type,
+                        legal.join(", ")
+                    ),
+                ));
+            }
+        };
+
+        check_data_type("min", self.min.is_some(), &NUMERIC_DATA_TYPES);
+        check_data_type("max", self.max.is_some(), &NUMERIC_DATA_TYPES);
+        check_data_type("min_length", self.min_length.is_some(), &SIZED_DATA_TYPES);
+        check_data_type("max_length", self.max_length.is_some(), &SIZED_DATA_TYPES);
+        check_data_type("pattern", self.pattern.is_some(), &TEXTUAL_DATA_TYPES);
+
+        if data_type_name == "integer" {
+            for (constraint, value) in [("min", &self.min), ("max", &self.max)] {
+                if let Some(value) = value {
+                    if !value.is_i64() && !value.is_u64() {
+                        violations.push(ConstraintProblem::new(
+                            constraint,
+                            format!("'{}' must be an integer, not {}", constraint, value),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (as_f64(&self.min), as_f64(&self.max)) {
+            if min > max {
+                violations.push(ConstraintProblem::new(
+                    "min",
+                    format!("'min' ({}) cannot be greater than 'max' ({})", min, max),
+                ));
+            }
+        }
+
+        if let (Some(min_length), Some(max_length)) = (self.min_length, self.max_length) {
+            if min_length > max_length {
+                violations.push(ConstraintProblem::new(
+                    "min_length",
+                    format!(
+                        "'min_length' ({}) cannot be greater than 'max_length' ({})",
+                        min_length, max_length
+                    ),
+                ));
+            }
+        }
+
+        let max_parameter = parsed_data_type
+            .as_ref()
+            .and_then(|parsed_data_type| parsed_data_type.parameter("max"))
+            .and_then(|max| max.parse::<u64>().ok());
+
+        if let (Some(max_length), Some(max_parameter)) = (self.max_length, max_parameter) {
+            if max_length > max_parameter {
+                violations.push(ConstraintProblem::new(
+                    "max_length",
+                    format!(
+                        "'max_length' ({}) cannot be greater than the data type's 'max' ({})",
+                        max_length, max_parameter
+                    ),
+                ));
+            }
+        }
+
+        if let Some(pattern) = &self.pattern {
+            if let Err(error) = Regex::new(pattern) {
+                violations.push(ConstraintProblem::new(
+                    "pattern",
+                    format!("'pattern' is not a valid regular expression: {}", error),
+                ));
+            }
+        }
+
+        violations
+    }
+
+    /// Constraints that are tighter than the tracked ones, i.e. that would reject values the tracked ones
+    /// accept, given whether the attribute is optional now and was when tracked. Making an optional attribute
+    /// mandatory is a tightening, given it would reject the attributes without value.
+    ///
+    /// Patterns cannot be compared, therefore a new pattern is a tightening and a changed one is reported as
+    /// such, given it cannot be verified to accept every value the tracked one accepts. Removing a pattern
+    /// only loosens the constraints.
+    pub fn tightenings(
+        &self,
+        optional: bool,
+        tracked: &Constraints,
+        tracked_optional: bool,
+    ) -> Vec<ConstraintProblem> {
+        let mut tightenings: Vec<ConstraintProblem> = Vec::new();
+
+        if tracked_optional && !optional {
+            tightenings.push(ConstraintProblem::new(
+                "optional",
+                "'optional' has been changed from true to false".to_string(),
+            ));
+        }
+
+        let mut check_bound = |constraint: &'static str,
+                               current: Option<f64>,
+                               tracked: Option<f64>,
+                               is_lower: bool| {
+            let message = match (tracked, current) {
+                (None, Some(current)) => format!("'{}' has been set to {}", constraint, current),
+                (Some(tracked), Some(current)) if is_lower && current > tracked => format!(
+                    "'{}' has been raised from {} to {}",
+                    constraint, tracked, current
+                ),
+                (Some(tracked), Some(current)) if !is_lower && current < tracked => format!(
+                    "'{}' has been lowered from {} to {}",
+                    constraint, tracked, current
+                ),
+                _ => return,
+            };
+
+            tightenings.push(ConstraintProblem::new(constraint, message));
+        };
+
+        check_bound("min", as_f64(&self.min), as_f64(&tracked.min), true);
+        check_bound("max", as_f64(&self.max), as_f64(&tracked.max), false);
+        check_bound(
+            "min_length",
+            self.min_length.map(|min_length| min_length as f64),
+            tracked.min_length.map(|min_length| min_length as f64),
+            true,
+        );
+        check_bound(
+            "max_length",
+            self.max_length.map(|max_length| max_length as f64),
+            tracked.max_length.map(|max_length| max_length as f64),
+            false,
+        );
+
+        match (&tracked.pattern, &self.pattern) {
+            (None, Some(pattern)) => tightenings.push(ConstraintProblem::new(
+                "pattern",
+                format!("'pattern' has been set to '{}'", pattern),
+            )),
+            (Some(tracked_pattern), Some(pattern)) if tracked_pattern != pattern => tightenings
+                .push(ConstraintProblem::new(
+                    "pattern",
+                    format!(
+                        "'pattern' has been changed from '{}' to '{}', which cannot be verified to accept every value the tracked one accepts",
+                        tracked_pattern, pattern
+                    ),
+                )),
+            // Removing the pattern is a loosening.
+            (Some(_), None) => (),
+            _ => (),
+        }
+
+        tightenings
+    }
+}
+
+fn as_f64(number: &Option<Number>) -> Option<f64> {
+    number.as_ref().and_then(|number| number.as_f64())
+}
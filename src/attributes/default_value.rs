@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde_json::{Number, Value};
+
+use crate::type_system::{DataType, NUMERIC_DATA_TYPES};
+
+use super::attribute_extension::AttributeExtension;
+use super::constraints::Constraints;
+
+/// Checks default values against the data type, unit and constraints of their attribute.
+///
+/// Defaults are written as JSON values of their data type's kind: `true` for a `boolean`, `5` for an
+/// `integer`, `"red"` for an `enum` or `["a", "b"]` for a `list<string>`. Any other data type, e.g. `date`,
+/// expects a string, which must match the data type's format if one is set. Numeric defaults of an attribute
+/// with a unit can also be written alongside it, e.g. `"5 kg"`.
+pub struct DefaultValueChecker {
+    data_type_formats: HashMap<String, String>,
+}
+
+impl DefaultValueChecker {
+    /// # Arguments
+    ///
+    /// * `data_type_formats` - regular expression that the defaults of each data type must match, with the
+    ///   data type's name as key.
+    pub fn new(data_type_formats: HashMap<String, String>) -> DefaultValueChecker {
+        DefaultValueChecker { data_type_formats }
+    }
+
+    /// Reasons why the default does not suit the attribute, empty if it does.
+    pub fn violations(
+        &self,
+        default: &Value,
+        data_type: &DataType,
+        unit: Option<&str>,
+        extension: &AttributeExtension,
+    ) -> Vec<String> {
+        let mut violations: Vec<String> = Vec::new();
+        let mut value = default.clone();
+
+        let unit_split = match default {
+            Value::String(text) if NUMERIC_DATA_TYPES.contains(&data_type.name()) => {
+                split_unit(text)
+            }
+            _ => None,
+        };
+
+        if let Some((number, written_unit)) = unit_split {
+            match unit {
+                Some(unit) if unit == written_unit => (),
+                Some(unit) => violations.push(format!(
+                    "it is written in '{}' rather than in the attribute's unit '{}'",
+                    written_unit, unit
+                )),
+                None => violations.push(format!(
+                    "it is written in '{}' while the attribute has no unit",
+                    written_unit
+                )),
+            }
+
+            value = Value::Number(number);
+        }
+
+        let option_names: Vec<String> = extension
+            .options
+            .iter()
+            .map(|option| option.name.clone())
+            .collect();
+
+        violations.extend(self.data_type_violations(&value, data_type, &option_names));
+
+        // Constraints only make sense once the default is of the right kind.
+        if violations.is_empty() {
+            violations.extend(constraint_violations(&value, &extension.constraints));
+        }
+
+        violations
+    }
+
+    fn data_type_violations(
+        &self,
+        value: &Value,
+        data_type: &DataType,
+        option_names: &[String],
+    ) -> Vec<String> {
+        let expected = match data_type {
+            DataType::List { item } => {
+                return match value {
+                    Value::Array(items) => items
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(index, item_value)| {
+                            self.data_type_violations(item_value, item, option_names)
+                                .into_iter()
+                                .map(move |violation| format!("item {}: {}", index, violation))
+                        })
+                        .collect(),
+                    _ => vec![format!("{} is not a list", value)],
+                };
+            }
+            DataType::Enum { options } => return option_violations(value, options),
+            DataType::Named { name, .. } if name == "enum" => {
+                return option_violations(value, option_names)
+            }
+            DataType::Named { name, .. } => name.as_str(),
+        };
+
+        match (expected, value) {
+            ("boolean", Value::Bool(_)) => Vec::new(),
+            ("integer", Value::Number(number)) if number.is_i64() || number.is_u64() => Vec::new(),
+            ("decimal", Value::Number(number)) => decimal_violations(number, data_type),
+            ("string", Value::String(text)) => match data_type
+                .parameter("max")
+                .and_then(|max| max.parse::<usize>().ok())
+            {
+                Some(max) if text.chars().count() > max => {
+                    vec![format!("it is longer than the data type's 'max' ({})", max)]
+                }
+                _ => Vec::new(),
+            },
+            ("boolean", _) | ("integer", _) | ("decimal", _) | ("string", _) => {
+                vec![format!("{} is not a valid {}", value, expected)]
+            }
+            (_, Value::String(text)) => match self.data_type_formats.get(expected) {
+                Some(format) => match Regex::new(format) {
+                    Ok(regex) if regex.is_match(text) => Vec::new(),
+                    Ok(_) => vec![format!(
+                        "'{}' does not match the format of '{}': {}",
+                        text, expected, format
+                    )],
+                    Err(error) => vec![format!(
+                        "the format of '{}' is not a valid regular expression: {}",
+                        expected, error
+                    )],
+                },
+                None => Vec::new(),
+            },
+            _ => vec![format!(
+                "{} is not a valid {}, which is written as a string",
+                value, expected
+            )],
+        }
+    }
+}
+
+/// Splits a numeric default written alongside its unit, e.g. `"5 kg"`.
+fn split_unit(text: &str) -> Option<(Number, &str)> {
+    match text.trim().split_once(' ') {
+        Some((number, unit)) => match serde_json::from_str::<Number>(number) {
+            Ok(number) if !unit.trim().is_empty() => Some((number, unit.trim())),
+            _ => None,
+        },
+        None => None,
+    }
+}
+
+fn option_violations(value: &Value, options: &[String]) -> Vec<String> {
+    match value {
+        Value::String(option) if options.contains(option) => Vec::new(),
+        _ => vec![format!(
+            "{} is not one of the options: {}",
+            value,
+            options.join(", ")
+        )],
+    }
+}
+
+/// Violations of the decimal's `precision` and `scale`, if set.
+fn decimal_violations(number: &Number, data_type: &DataType) -> Vec<String> {
+    let mut violations: Vec<String> = Vec::new();
+    let written = number.to_string();
+
+    // Numbers written with an exponent are too large or small to be compared digit by digit.
+    if written.contains(['e', 'E']) {
+        return violations;
+    }
+
+    let (integer_digits, fraction_digits) = match written.trim_start_matches('-').split_once('.') {
+        Some((integer, fraction)) => (integer.trim_start_matches('0').len(), fraction.len()),
+        None => (
+            written
+                .trim_start_matches('-')
+                .trim_start_matches('0')
+                .len(),
+            0,
+        ),
+    };
+    let parameter = |name: &str| {
+        data_type
+            .parameter(name)
+            .and_then(|value| value.parse::<usize>().ok())
+    };
+
+    if let Some(scale) = parameter("scale") {
+        if fraction_digits > scale {
+            violations.push(format!(
+                "it has {} digits after the decimal point, more than the data type's 'scale' ({})",
+                fraction_digits, scale
+            ));
+        }
+    }
+
+    if let Some(precision) = parameter("precision") {
+        let scale = parameter("scale").unwrap_or(0);
+
+        if integer_digits + scale.max(fraction_digits) > precision {
+            violations.push(format!(
+                "it has more digits than the data type's 'precision' ({})",
+                precision
+            ));
+        }
+    }
+
+    violations
+}
+
+fn constraint_violations(value: &Value, constraints: &Constraints) -> Vec<String> {
+    let mut violations: Vec<String> = Vec::new();
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = constraints.min.as_ref().and_then(|min| min.as_f64()) {
+            if number < min {
+                violations.push(format!("it is lower than 'min' ({})", min));
+            }
+        }
+
+        if let Some(max) = constraints.max.as_ref().and_then(|max| max.as_f64()) {
+            if number > max {
+                violations.push(format!("it is greater than 'max' ({})", max));
+            }
+        }
+    }
+
+    let length = match value {
+        Value::String(text) => Some(text.chars().count() as u64),
+        Value::Array(items) => Some(items.len() as u64),
+        _ => None,
+    };
+
+    if let Some(length) = length {
+        if let Some(min_length) = constraints.min_length {
+            if length < min_length {
+                violations.push(format!("it is shorter than 'min_length' ({})", min_length));
+            }
+        }
+
+        if let Some(max_length) = constraints.max_length {
+            if length > max_length {
+                violations.push(format!("it is longer than 'max_length' ({})", max_length));
+            }
+        }
+    }
+
+    if let (Value::String(text), Some(pattern)) = (value, &constraints.pattern) {
+        // Invalid patterns are reported by the constraints' own validation.
+        if let Ok(regex) = Regex::new(pattern) {
+            if !regex.is_match(text) {
+                violations.push(format!("it does not match 'pattern' ({})", pattern));
+            }
+        }
+    }
+
+    violations
+}
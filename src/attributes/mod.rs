@@ -1,5 +1,13 @@
+pub mod attribute_extension;
 pub mod attribute_id_generator;
 pub mod attribute_id_tracker;
 pub mod attribute_tracker_file_io;
 pub mod attribute_tracker_io;
+pub mod attribute_tracker_memory_io;
+pub mod constraints;
+pub mod default_value;
+pub mod option_id_generator;
+pub mod option_tracker_file_io;
+pub mod option_tracker_io;
+pub mod option_tracker_memory_io;
 pub mod validations;
@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+use crate::error::{Error, ErrorKind};
+
+use super::attribute_extension::AttributeOption;
+
+/// Sets a random id to the option.
+///
+/// Error kinds:
+///
+/// * `CannotOverrideId`: if the option has already an id.
+pub fn set_random_id(option: &mut AttributeOption) -> Result<(), Error> {
+    match option.id {
+        Some(_) => Err(Error::new(
+            ErrorKind::CannotOverrideId,
+            "option already has an id",
+        )),
+        None => {
+            option.id = Some(Uuid::new_v4().to_string());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    collections::HashMap,
+    io::{Error, ErrorKind},
+};
+
+use super::attribute_tracker_file_io::{escape_field, split_fields};
+use super::option_tracker_io::{OptionEntry, OptionTrackerIO};
+
+pub struct OptionTrackerFileIO {
+    path: String,
+}
+
+impl OptionTrackerFileIO {
+    pub fn new(path: &str) -> OptionTrackerFileIO {
+        OptionTrackerFileIO {
+            path: path.to_string(),
+        }
+    }
+}
+
+impl OptionTrackerIO for OptionTrackerFileIO {
+    /// Proceeds to read the entries from a file that contains the id of an option, the id of its attribute and
+    /// its name, separated by ';', i.e. "1234-1234-1234;5678-5678-5678;red".
+    ///
+    /// Within every field, ';' and '\' are escaped with a '\', and line breaks are written as "\n" and "\r".
+    ///
+    /// A missing file is read as no entries, given options may have never been tracked.
+    ///
+    /// # Returns
+    ///
+    /// `Ok`: `HashMap` where the keys, are the id of the option, and the value are instances of `OptionEntry`.
+    /// `Err`: error detailing why the function has failed.
+    fn read_entries(&self) -> Result<HashMap<String, OptionEntry>, Error> {
+        let mut entries: HashMap<String, OptionEntry> = HashMap::new();
+
+        match std::fs::read_to_string(self.path.as_str()) {
+            Ok(content) => {
+                for line in content.lines() {
+                    if line.is_empty() {
+                        continue;
+                    }
+
+                    let result: Vec<String> = split_fields(line, usize::MAX);
+
+                    if result.len() != 3 {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            "Option id tracker file contains a malformed line.",
+                        ));
+                    }
+
+                    entries.insert(
+                        result[0].to_string(),
+                        OptionEntry {
+                            id: result[0].to_string(),
+                            attribute_id: result[1].to_string(),
+                            name: result[2].to_string(),
+                        },
+                    );
+                }
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => (),
+            Err(error) => {
+                return Err(error);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn write_entry(&self, entry: &OptionEntry) -> Result<(), Error> {
+        let current_content = match std::fs::read_to_string(self.path.as_str()) {
+            Ok(current_content) => current_content,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let line = format!(
+            "{};{};{}",
+            escape_field(entry.id.as_str()),
+            escape_field(entry.attribute_id.as_str()),
+            escape_field(entry.name.as_str())
+        );
+
+        let new_content = if current_content.is_empty() {
+            line
+        } else {
+            format!("{}\n{}", current_content, line)
+        };
+
+        std::fs::write(self.path.as_str(), new_content)
+    }
+}
@@ -0,0 +1,15 @@
+use std::{collections::HashMap, io::Error};
+
+/// Tracked option of an enumerated attribute.
+#[derive(Clone)]
+pub struct OptionEntry {
+    pub id: String,
+    /// Id of the attribute the option belongs to.
+    pub attribute_id: String,
+    pub name: String,
+}
+
+pub trait OptionTrackerIO {
+    fn read_entries(&self) -> Result<HashMap<String, OptionEntry>, Error>;
+    fn write_entry(&self, entry: &OptionEntry) -> Result<(), Error>;
+}
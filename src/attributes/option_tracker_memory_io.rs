@@ -0,0 +1,30 @@
+use std::{cell::RefCell, collections::HashMap, io::Error};
+
+use super::option_tracker_io::{OptionEntry, OptionTrackerIO};
+
+/// In-memory option tracking storage, used for running the CI logic without writing any file.
+pub struct OptionTrackerMemoryIO {
+    entries: RefCell<HashMap<String, OptionEntry>>,
+}
+
+impl OptionTrackerMemoryIO {
+    pub fn new(entries: HashMap<String, OptionEntry>) -> OptionTrackerMemoryIO {
+        OptionTrackerMemoryIO {
+            entries: RefCell::new(entries),
+        }
+    }
+}
+
+impl OptionTrackerIO for OptionTrackerMemoryIO {
+    fn read_entries(&self) -> Result<HashMap<String, OptionEntry>, Error> {
+        Ok(self.entries.borrow().clone())
+    }
+
+    fn write_entry(&self, entry: &OptionEntry) -> Result<(), Error> {
+        self.entries
+            .borrow_mut()
+            .insert(entry.id.clone(), entry.clone());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::attribute_tracker_io::AttributeEntry,
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+};
+
+use super::validation::Validation;
+
+/// Validates whether an attribute has been moved to another category since it was tracked.
+///
+/// Attributes whose entries predate the tracking of categories are not validated.
+pub struct AttributeCategoryConstantValidation {
+    expected_category_ids: HashMap<String, String>,
+}
+
+impl AttributeCategoryConstantValidation {
+    pub fn new(entries: &HashMap<String, AttributeEntry>) -> AttributeCategoryConstantValidation {
+        let mut expected_category_ids: HashMap<String, String> = HashMap::new();
+
+        for entry in entries.values() {
+            if let Some(category_id) = &entry.category_id {
+                expected_category_ids.insert(entry.id.clone(), category_id.clone());
+            }
+        }
+
+        AttributeCategoryConstantValidation {
+            expected_category_ids,
+        }
+    }
+}
+
+impl Validation for AttributeCategoryConstantValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            if let Some(expected_category_id) = self.expected_category_ids.remove(&attribute.id) {
+                if expected_category_id != category.id {
+                    diagnostics.push(
+                        Diagnostic::for_attribute(
+                            ErrorKind::AttributeCategoryChanged,
+                            format!(
+                                "attribute '{}' with id '{}' has been moved from the category with id '{}' to '{}'",
+                                attribute.name, attribute.id, expected_category_id, category.name
+                            )
+                            .as_str(),
+                            category,
+                            attribute,
+                        )
+                        .with_details(Some(ErrorDetails::CategoryChange {
+                            from: expected_category_id,
+                            to: category.id.clone(),
+                        })),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
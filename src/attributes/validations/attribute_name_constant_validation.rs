@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::attribute_tracker_io::AttributeEntry,
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+};
+
+use super::validation::Validation;
+
+/// Validates whether an attribute has been renamed since it was tracked.
+///
+/// Attributes whose entries predate the tracking of names are not validated.
+pub struct AttributeNameConstantValidation {
+    expected_names: HashMap<String, String>,
+}
+
+impl AttributeNameConstantValidation {
+    pub fn new(entries: &HashMap<String, AttributeEntry>) -> AttributeNameConstantValidation {
+        let mut expected_names: HashMap<String, String> = HashMap::new();
+
+        for entry in entries.values() {
+            if let Some(name) = &entry.name {
+                expected_names.insert(entry.id.clone(), name.clone());
+            }
+        }
+
+        AttributeNameConstantValidation { expected_names }
+    }
+}
+
+impl Validation for AttributeNameConstantValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            if let Some(expected_name) = self.expected_names.remove(&attribute.id) {
+                if expected_name != attribute.name {
+                    diagnostics.push(
+                        Diagnostic::for_attribute(
+                            ErrorKind::AttributeRenamed,
+                            format!(
+                                "attribute with id '{}' has been renamed from '{}' to '{}'",
+                                attribute.id, expected_name, attribute.name
+                            )
+                            .as_str(),
+                            category,
+                            attribute,
+                        )
+                        .with_details(Some(ErrorDetails::NameChange {
+                            from: expected_name,
+                            to: attribute.name.clone(),
+                        })),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
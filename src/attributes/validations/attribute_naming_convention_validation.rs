@@ -0,0 +1,65 @@
+use cooplan_definitions_lib::category::Category;
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::naming_convention::{NamingConvention, NamingRules};
+
+use super::validation::Validation;
+
+/// Validates that attribute names follow the naming convention set within the configuration, if any.
+pub struct AttributeNamingConventionValidation {
+    rules: Result<Option<NamingRules>, Error>,
+}
+
+impl AttributeNamingConventionValidation {
+    pub fn new(convention: Option<NamingConvention>) -> AttributeNamingConventionValidation {
+        let rules = match convention {
+            Some(convention) => NamingRules::try_new(&convention).map(Some),
+            None => Ok(None),
+        };
+
+        AttributeNamingConventionValidation { rules }
+    }
+}
+
+impl Validation for AttributeNamingConventionValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let rules = match &self.rules {
+            Ok(Some(rules)) => rules,
+            Ok(None) => return Ok(()),
+            Err(error) => return Err(Error::new(error.kind(), error.full_message().as_str())),
+        };
+
+        for attribute in category.attributes.as_slice() {
+            let violations = rules.violations(attribute.name.as_str());
+
+            if !violations.is_empty() {
+                diagnostics.push(
+                    Diagnostic::for_attribute(
+                        ErrorKind::NamingConventionViolated,
+                        format!(
+                            "attribute name '{}' with id '{}' {}",
+                            attribute.name,
+                            attribute.id,
+                            violations.join(", ")
+                        )
+                        .as_str(),
+                        category,
+                        attribute,
+                    )
+                    .with_details(Some(ErrorDetails::NamingConvention { violations })),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::{
+        attribute_extension::AttributeExtension, attribute_tracker_io::AttributeEntry,
+        constraints::Constraints,
+    },
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+};
+
+use super::validation::Validation;
+
+/// Validates that the constraints of tracked attributes, including whether they are optional, have only been
+/// loosened, given that tighter constraints would reject values that have already been accepted.
+///
+/// Attributes whose entries predate the tracking of constraints are not validated.
+pub struct ConstraintTighteningValidation {
+    /// Tracked constraints and, if tracked, whether the attribute was optional, with the attribute's id as key.
+    tracked_constraints: HashMap<String, (Constraints, Option<bool>)>,
+    attribute_extensions: HashMap<String, AttributeExtension>,
+}
+
+impl ConstraintTighteningValidation {
+    pub fn new(
+        entries: &HashMap<String, AttributeEntry>,
+        attribute_extensions: HashMap<String, AttributeExtension>,
+    ) -> ConstraintTighteningValidation {
+        let mut tracked_constraints: HashMap<String, (Constraints, Option<bool>)> = HashMap::new();
+
+        for entry in entries.values() {
+            if let Some(constraints) = &entry.constraints {
+                tracked_constraints.insert(entry.id.clone(), (constraints.clone(), entry.optional));
+            }
+        }
+
+        ConstraintTighteningValidation {
+            tracked_constraints,
+            attribute_extensions,
+        }
+    }
+}
+
+impl Validation for ConstraintTighteningValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let (tracked_constraints, tracked_optional) =
+                match self.tracked_constraints.remove(&attribute.id) {
+                    Some(tracked_constraints) => tracked_constraints,
+                    None => continue,
+                };
+
+            let constraints = match self.attribute_extensions.get(&attribute.id) {
+                Some(attribute_extension) => attribute_extension.constraints.clone(),
+                None => Constraints::default(),
+            };
+
+            for tightening in constraints.tightenings(
+                attribute.optional,
+                &tracked_constraints,
+                tracked_optional.unwrap_or(attribute.optional),
+            ) {
+                diagnostics.push(
+                    Diagnostic::for_attribute(
+                        ErrorKind::ConstraintTightened,
+                        format!(
+                            "attribute '{}' with id '{}' has a tighter constraint than the tracked one: {}",
+                            attribute.name, attribute.id, tightening.message
+                        )
+                        .as_str(),
+                        category,
+                        attribute,
+                    )
+                    .with_details(Some(ErrorDetails::Constraint {
+                        constraint: tightening.constraint.to_string(),
+                    })),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
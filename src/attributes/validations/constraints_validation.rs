@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::attribute_extension::AttributeExtension,
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+};
+
+use super::validation::Validation;
+
+/// Validates that the constraints of each attribute are legal for its data type and consistent with each
+/// other, e.g. that a `pattern` is only set for a `string` and is a valid regular expression.
+pub struct ConstraintsValidation {
+    attribute_extensions: HashMap<String, AttributeExtension>,
+}
+
+impl ConstraintsValidation {
+    /// # Arguments
+    ///
+    /// * `attribute_extensions` - extension of each attribute that has any, with the attribute's id as key.
+    pub fn new(attribute_extensions: HashMap<String, AttributeExtension>) -> ConstraintsValidation {
+        ConstraintsValidation {
+            attribute_extensions,
+        }
+    }
+}
+
+impl Validation for ConstraintsValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let constraints = match self.attribute_extensions.get(&attribute.id) {
+                Some(attribute_extension) => &attribute_extension.constraints,
+                None => continue,
+            };
+
+            for violation in constraints.violations(attribute.data_type.as_str()) {
+                diagnostics.push(
+                    Diagnostic::for_attribute(
+                        ErrorKind::InvalidConstraint,
+                        format!(
+                            "attribute '{}' with id '{}' has an invalid constraint: {}",
+                            attribute.name, attribute.id, violation.message
+                        )
+                        .as_str(),
+                        category,
+                        attribute,
+                    )
+                    .with_details(Some(ErrorDetails::Constraint {
+                        constraint: violation.constraint.to_string(),
+                    })),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
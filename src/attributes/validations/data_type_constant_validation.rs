@@ -1,20 +1,30 @@
 use std::collections::HashMap;
 
+use cooplan_definitions_lib::category::Category;
+
 use crate::{
     attributes::attribute_tracker_io::AttributeEntry,
-    error::{Error, ErrorKind},
+    data_type_widenings::DataTypeWidenings,
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+    type_system::same_data_type,
 };
 
 use super::validation::Validation;
 
 /// Validates whether an attribute's data type has changed since it was created.
+///
+/// Compatible changes, i.e. widenings such as `integer` to `decimal`, are accepted.
 pub struct DataTypeConstantValidation {
     expected_data_types: HashMap<String, String>,
-    failed_validations: Vec<Error>,
+    widenings: DataTypeWidenings,
 }
 
 impl DataTypeConstantValidation {
-    pub fn new(entries: &HashMap<String, AttributeEntry>) -> DataTypeConstantValidation {
+    pub fn new(
+        entries: &HashMap<String, AttributeEntry>,
+        widenings: DataTypeWidenings,
+    ) -> DataTypeConstantValidation {
         let mut expected_data_type: HashMap<String, String> = HashMap::new();
 
         for entry in entries {
@@ -23,7 +33,7 @@ impl DataTypeConstantValidation {
 
         DataTypeConstantValidation {
             expected_data_types: expected_data_type,
-            failed_validations: Vec::new(),
+            widenings,
         }
     }
 }
@@ -31,31 +41,46 @@ impl DataTypeConstantValidation {
 impl Validation for DataTypeConstantValidation {
     fn partially_validate(
         &mut self,
-        attributes: &[cooplan_definitions_lib::attribute::Attribute],
+        category: &Category,
+        diagnostics: &mut Diagnostics,
     ) -> Result<(), Error> {
-        for attribute in attributes {
-            match self.expected_data_types.remove(&attribute.id) {
-                Some(expected_data_type) => {
-                    if expected_data_type.ne(&attribute.data_type) {
-                        self.failed_validations.push(Error::new(
+        for attribute in category.attributes.as_slice() {
+            // Untracked and duplicated ids are reported by the id tracking validation.
+            if let Some(expected_data_type) = self.expected_data_types.remove(&attribute.id) {
+                if !same_data_type(expected_data_type.as_str(), attribute.data_type.as_str())
+                    && !self
+                        .widenings
+                        .allows(expected_data_type.as_str(), attribute.data_type.as_str())
+                {
+                    let change = if self
+                        .widenings
+                        .allows(attribute.data_type.as_str(), expected_data_type.as_str())
+                    {
+                        "narrowed"
+                    } else {
+                        "changed"
+                    };
+
+                    diagnostics.push(
+                        Diagnostic::for_attribute(
                             ErrorKind::FailedDataTypeConstantAttributeValidation,
                             format!(
-                                "attribute '{}' with id '{}' changed its data type from '{}' to '{}'",
+                                "attribute '{}' with id '{}' {} its data type from '{}' to '{}'",
                                 attribute.name,
                                 attribute.id,
+                                change,
                                 expected_data_type,
                                 attribute.data_type
                             )
                             .as_str(),
-                        ));
-                    }
-                }
-                None => {
-                    return Err(Error::new(
-                        ErrorKind::DuplicatedId,
-                        format!("attribute's id '{}' has been validated twice", attribute.id)
-                            .as_str(),
-                    ))
+                            category,
+                            attribute,
+                        )
+                        .with_details(Some(ErrorDetails::DataTypeChange {
+                            from: expected_data_type,
+                            to: attribute.data_type.clone(),
+                        })),
+                    );
                 }
             }
         }
@@ -63,21 +88,7 @@ impl Validation for DataTypeConstantValidation {
         Ok(())
     }
 
-    fn complete(&mut self) -> Result<(), Error> {
-        if self.failed_validations.is_empty() {
-            return Ok(());
-        }
-
-        let mut error_accumulation: String = String::new();
-
-        for error in self.failed_validations.as_slice() {
-            error_accumulation.push('\n');
-            error_accumulation.push_str(error.message.as_str());
-        }
-
-        Err(Error::new(
-            ErrorKind::FailedDataTypeConstantAttributeValidation,
-            &error_accumulation,
-        ))
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
     }
 }
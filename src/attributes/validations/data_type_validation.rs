@@ -1,68 +1,69 @@
 use cooplan_definitions_lib::attribute::Attribute;
+use cooplan_definitions_lib::category::Category;
 
-use crate::error::{Error, ErrorKind};
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::type_system::{DataType, TypeSystem};
 
 use super::validation::Validation;
 
-/// Detects whether or not an attribute's data type is valid.
+/// Detects whether or not an attribute's data type is valid, i.e. whether it follows the data types'
+/// grammar, is made of valid data types and has valid parameters.
 pub struct DataTypeValidation {
-    failed_validations: Vec<Error>,
-    valid_data_types: Vec<String>,
+    type_system: TypeSystem,
 }
 
 impl DataTypeValidation {
     pub fn new(valid_data_types: Vec<String>) -> DataTypeValidation {
         DataTypeValidation {
-            failed_validations: Vec::new(),
-            valid_data_types,
+            type_system: TypeSystem::new(valid_data_types),
         }
     }
 
-    fn validate_attribute_data_type(&self, attribute: &Attribute) -> Result<(), Error> {
-        for valid_data_type in self.valid_data_types.as_slice() {
-            if valid_data_type.eq(&attribute.data_type) {
-                return Ok(());
-            }
+    /// Problems of the attribute's data type, empty if it is valid.
+    fn data_type_violations(&self, attribute: &Attribute) -> Vec<String> {
+        match DataType::parse(attribute.data_type.as_str()) {
+            Ok(data_type) => self.type_system.violations(&data_type),
+            Err(error) => vec![error.message],
         }
-
-        Err(Error::new(
-            ErrorKind::FailedDataTypeAttributeValidation,
-            format!(
-                "attribute '{}' with id '{}' has an invalid data type: {}",
-                attribute.name, attribute.id, attribute.data_type
-            )
-            .as_str(),
-        ))
     }
 }
 
 impl Validation for DataTypeValidation {
-    fn partially_validate(&mut self, attributes: &[Attribute]) -> Result<(), Error> {
-        for attribute in attributes {
-            match self.validate_attribute_data_type(attribute) {
-                Ok(_) => (),
-                Err(error) => self.failed_validations.push(error),
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let violations = self.data_type_violations(attribute);
+
+            if !violations.is_empty() {
+                diagnostics.push(
+                    Diagnostic::for_attribute(
+                        ErrorKind::FailedDataTypeAttributeValidation,
+                        format!(
+                            "attribute '{}' with id '{}' has an invalid data type: {} ({})",
+                            attribute.name,
+                            attribute.id,
+                            attribute.data_type,
+                            violations.join(", ")
+                        )
+                        .as_str(),
+                        category,
+                        attribute,
+                    )
+                    .with_details(Some(ErrorDetails::DataType {
+                        data_type: attribute.data_type.clone(),
+                    })),
+                );
             }
         }
 
         Ok(())
     }
 
-    fn complete(&mut self) -> Result<(), Error> {
-        if self.failed_validations.is_empty() {
-            return Ok(());
-        }
-
-        let mut error_accumulation: String = String::new();
-
-        for error in self.failed_validations.as_slice() {
-            error_accumulation.push('\n');
-            error_accumulation.push_str(error.message.as_str());
-        }
-
-        Err(Error::new(
-            ErrorKind::FailedDataTypeAttributeValidation,
-            error_accumulation.as_str(),
-        ))
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
     }
 }
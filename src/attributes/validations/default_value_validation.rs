@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::{attribute_extension::AttributeExtension, default_value::DefaultValueChecker},
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+    type_system::DataType,
+};
+
+use super::validation::Validation;
+
+/// Validates that default values are of their attribute's data type, and respect its unit and constraints.
+///
+/// Attributes whose data type is not valid are not validated, given their data type is reported already.
+pub struct DefaultValueValidation {
+    attribute_extensions: HashMap<String, AttributeExtension>,
+    checker: DefaultValueChecker,
+}
+
+impl DefaultValueValidation {
+    /// # Arguments
+    ///
+    /// * `attribute_extensions` - extension of each attribute that has any, with the attribute's id as key.
+    /// * `data_type_formats` - regular expression that the defaults of each data type must match, with the
+    ///   data type's name as key.
+    pub fn new(
+        attribute_extensions: HashMap<String, AttributeExtension>,
+        data_type_formats: HashMap<String, String>,
+    ) -> DefaultValueValidation {
+        DefaultValueValidation {
+            attribute_extensions,
+            checker: DefaultValueChecker::new(data_type_formats),
+        }
+    }
+}
+
+impl Validation for DefaultValueValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let (extension, default) = match self.attribute_extensions.get(&attribute.id) {
+                Some(extension) => match &extension.default {
+                    Some(default) => (extension, default),
+                    None => continue,
+                },
+                None => continue,
+            };
+
+            let data_type = match DataType::parse(attribute.data_type.as_str()) {
+                Ok(data_type) => data_type,
+                Err(_) => continue,
+            };
+
+            let violations = self.checker.violations(
+                default,
+                &data_type,
+                attribute.unit.as_deref().filter(|unit| !unit.is_empty()),
+                extension,
+            );
+
+            if violations.is_empty() {
+                continue;
+            }
+
+            diagnostics.push(
+                Diagnostic::for_attribute(
+                    ErrorKind::InvalidDefaultValue,
+                    format!(
+                        "attribute '{}' with id '{}' has an invalid default value {} for its data type '{}': {}",
+                        attribute.name,
+                        attribute.id,
+                        default,
+                        attribute.data_type,
+                        violations.join(", ")
+                    )
+                    .as_str(),
+                    category,
+                    attribute,
+                )
+                .with_details(Some(ErrorDetails::DefaultValue {
+                    value: default.to_string(),
+                    violations,
+                })),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
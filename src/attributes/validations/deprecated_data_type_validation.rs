@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::type_system::data_type_name;
+
+use super::validation::Validation;
+
+/// Detects attributes whose data type is deprecated, so they can be migrated before the data type is
+/// removed from the valid ones.
+pub struct DeprecatedDataTypeValidation {
+    deprecated_data_types: HashMap<String, String>,
+}
+
+impl DeprecatedDataTypeValidation {
+    /// # Arguments
+    ///
+    /// * `deprecated_data_types` - explanation of each deprecated data type, with the data type as key.
+    pub fn new(deprecated_data_types: HashMap<String, String>) -> DeprecatedDataTypeValidation {
+        DeprecatedDataTypeValidation {
+            deprecated_data_types,
+        }
+    }
+}
+
+impl Validation for DeprecatedDataTypeValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let explanation = self
+                .deprecated_data_types
+                .get(&attribute.data_type)
+                .or_else(|| {
+                    self.deprecated_data_types
+                        .get(&data_type_name(attribute.data_type.as_str()))
+                });
+
+            if let Some(explanation) = explanation {
+                diagnostics.push(
+                    Diagnostic::for_attribute(
+                        ErrorKind::DeprecatedDataType,
+                        format!(
+                            "attribute '{}' with id '{}' has a deprecated data type '{}': {}",
+                            attribute.name, attribute.id, attribute.data_type, explanation
+                        )
+                        .as_str(),
+                        category,
+                        attribute,
+                    )
+                    .with_details(Some(ErrorDetails::DataType {
+                        data_type: attribute.data_type.clone(),
+                    })),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use cooplan_definitions_lib::attribute::Attribute;
+use cooplan_definitions_lib::category::Category;
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+
+use super::validation::Validation;
+
+/// Validates that attribute names are unique, given product forms merge inherited attributes by name:
+///
+/// * Within a category - no two attributes of the same category share the same name.
+/// * Across the parent chain - no attribute shares the name of an attribute of any of its ancestors.
+///
+/// Both attributes' ids are reported.
+#[derive(Default)]
+pub struct DuplicateAttributeNameValidation {}
+
+impl DuplicateAttributeNameValidation {
+    pub fn new() -> DuplicateAttributeNameValidation {
+        DuplicateAttributeNameValidation {}
+    }
+
+    fn validate_within_category(&self, category: &Category, diagnostics: &mut Diagnostics) {
+        for (index, attribute) in category.attributes.iter().enumerate() {
+            let duplicates: Vec<&Attribute> = category
+                .attributes
+                .iter()
+                .enumerate()
+                .filter(|(other_index, other)| {
+                    *other_index != index && other.name == attribute.name
+                })
+                .map(|(_, other)| other)
+                .collect();
+
+            if duplicates.is_empty() {
+                continue;
+            }
+
+            let mut ids = vec![attribute.id.clone()];
+            ids.extend(duplicates.iter().map(|duplicate| duplicate.id.clone()));
+
+            diagnostics.push(
+                Diagnostic::for_attribute(
+                    ErrorKind::DuplicatedAttributeName,
+                    format!(
+                        "attribute '{}' with id '{}' shares its name with the following attribute(s) of category '{}': {}",
+                        attribute.name,
+                        attribute.id,
+                        category.name,
+                        ids[1..].join(", ")
+                    )
+                    .as_str(),
+                    category,
+                    attribute,
+                )
+                .with_details(Some(ErrorDetails::Name {
+                    name: attribute.name.clone(),
+                    ids,
+                })),
+            );
+        }
+    }
+
+    fn validate_across_ancestors(
+        &self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let mut ancestor_pointer = category.parent.as_ref().and_then(|parent| parent.upgrade());
+
+            while let Some(pointer) = ancestor_pointer {
+                let ancestor = match pointer.try_borrow() {
+                    Ok(ancestor) => ancestor,
+                    Err(error) => {
+                        return Err(Error::new(
+                            ErrorKind::FailedToBorrowCategory,
+                            "failed to borrow category",
+                        )
+                        .with_source(error))
+                    }
+                };
+
+                let shadowed_attribute = ancestor
+                    .attributes
+                    .iter()
+                    .find(|ancestor_attribute| ancestor_attribute.name == attribute.name);
+
+                if let Some(shadowed_attribute) = shadowed_attribute {
+                    diagnostics.push(
+                        Diagnostic::for_attribute(
+                            ErrorKind::ShadowedAttributeName,
+                            format!(
+                                "attribute '{}' with id '{}' shadows the attribute with id '{}' inherited from category '{}'",
+                                attribute.name, attribute.id, shadowed_attribute.id, ancestor.name
+                            )
+                            .as_str(),
+                            category,
+                            attribute,
+                        )
+                        .with_details(Some(ErrorDetails::Name {
+                            name: attribute.name.clone(),
+                            ids: vec![attribute.id.clone(), shadowed_attribute.id.clone()],
+                        })),
+                    );
+
+                    break;
+                }
+
+                ancestor_pointer = ancestor.parent.as_ref().and_then(|parent| parent.upgrade());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validation for DuplicateAttributeNameValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        self.validate_within_category(category, diagnostics);
+
+        self.validate_across_ancestors(category, diagnostics)
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
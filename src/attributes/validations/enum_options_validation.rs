@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::attribute_extension::AttributeExtension,
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+    type_system::{DataType, ENUM},
+};
+
+use super::validation::Validation;
+
+/// Validates that attributes whose data type is `enum` declare their options, each of them with a unique
+/// name, and that no other attribute declares options.
+pub struct EnumOptionsValidation {
+    attribute_extensions: HashMap<String, AttributeExtension>,
+}
+
+impl EnumOptionsValidation {
+    /// # Arguments
+    ///
+    /// * `attribute_extensions` - extension of each attribute that has any, with the attribute's id as key.
+    pub fn new(attribute_extensions: HashMap<String, AttributeExtension>) -> EnumOptionsValidation {
+        EnumOptionsValidation {
+            attribute_extensions,
+        }
+    }
+}
+
+impl Validation for EnumOptionsValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let options = match self.attribute_extensions.get(&attribute.id) {
+                Some(attribute_extension) => attribute_extension.options.as_slice(),
+                None => &[],
+            };
+
+            // Options listed within the data type itself, i.e. `enum[red,green]`, are not declared.
+            let declares_options = matches!(
+                DataType::parse(attribute.data_type.as_str()),
+                Ok(DataType::Named { name, .. }) if name == ENUM
+            );
+
+            if declares_options && options.is_empty() {
+                diagnostics.push(Diagnostic::for_attribute(
+                    ErrorKind::MissingOptions,
+                    format!(
+                        "attribute '{}' with id '{}' has the data type '{}' without declaring any option",
+                        attribute.name, attribute.id, attribute.data_type
+                    )
+                    .as_str(),
+                    category,
+                    attribute,
+                ));
+            } else if !declares_options && !options.is_empty() {
+                diagnostics.push(Diagnostic::for_attribute(
+                    ErrorKind::UnexpectedOptions,
+                    format!(
+                        "attribute '{}' with id '{}' declares options, while its data type '{}' is not '{}'",
+                        attribute.name, attribute.id, attribute.data_type, ENUM
+                    )
+                    .as_str(),
+                    category,
+                    attribute,
+                ));
+            }
+
+            for (index, option) in options.iter().enumerate() {
+                if options[..index]
+                    .iter()
+                    .any(|previous_option| previous_option.name == option.name)
+                {
+                    let ids: Vec<String> = options
+                        .iter()
+                        .filter(|other_option| other_option.name == option.name)
+                        .filter_map(|other_option| other_option.id.clone())
+                        .collect();
+
+                    diagnostics.push(
+                        Diagnostic::for_attribute(
+                            ErrorKind::DuplicatedOptionName,
+                            format!(
+                                "attribute '{}' with id '{}' declares the option '{}' more than once",
+                                attribute.name, attribute.id, option.name
+                            )
+                            .as_str(),
+                            category,
+                            attribute,
+                        )
+                        .with_details(Some(ErrorDetails::Name {
+                            name: option.name.clone(),
+                            ids,
+                        })),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 
-use cooplan_definitions_lib::attribute::Attribute;
+use cooplan_definitions_lib::category::Category;
 
 use crate::{
     attributes::{
         attribute_id_tracker::AttributeIdTracker, attribute_tracker_io::AttributeEntry,
         validations::validation::Validation,
     },
+    diagnostics::{Diagnostic, Diagnostics},
     error::Error,
 };
 
@@ -24,21 +25,35 @@ impl IdTrackingValidation {
 }
 
 impl Validation for IdTrackingValidation {
-    fn partially_validate(&mut self, attributes: &[Attribute]) -> Result<(), Error> {
-        for attribute in attributes {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
             match self.attribute_id_tracker.track_attribute(&attribute.id) {
                 Ok(_) => (),
-                Err(error) => return Err(error),
+                Err(error) => diagnostics.push(
+                    Diagnostic::for_attribute(
+                        error.kind(),
+                        error.message.as_str(),
+                        category,
+                        attribute,
+                    )
+                    .with_details(error.details().cloned()),
+                ),
             }
         }
 
         Ok(())
     }
 
-    fn complete(&mut self) -> Result<(), Error> {
+    fn complete(&mut self, diagnostics: &mut Diagnostics) -> Result<(), Error> {
         match self.attribute_id_tracker.close() {
-            Ok(_) => Ok(()),
-            Err(error) => Err(error),
+            Ok(_) => (),
+            Err(error) => diagnostics.push(Diagnostic::from(error)),
         }
+
+        Ok(())
     }
 }
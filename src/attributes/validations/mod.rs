@@ -1,5 +1,17 @@
+pub mod attribute_category_constant_validation;
+pub mod attribute_name_constant_validation;
+pub mod attribute_naming_convention_validation;
+pub mod constraint_tightening_validation;
+pub mod constraints_validation;
 pub mod data_type_constant_validation;
 pub mod data_type_validation;
+pub mod default_value_validation;
+pub mod deprecated_data_type_validation;
+pub mod duplicate_attribute_name_validation;
+pub mod enum_options_validation;
 pub mod id_tracking_validation;
+pub mod option_id_tracking_validation;
 pub mod reserved_attribute_names_validation;
+pub mod unit_constant_validation;
+pub mod unit_validation;
 pub mod validation;
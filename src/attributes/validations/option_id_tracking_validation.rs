@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::{attribute_extension::AttributeExtension, option_tracker_io::OptionEntry},
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+};
+
+use super::validation::Validation;
+
+/// Validates that the options of enumerated attributes are tracked, and that once tracked they are never
+/// removed nor reused, either by another attribute or for another name.
+pub struct OptionIdTrackingValidation {
+    entries: HashMap<String, OptionEntry>,
+    attribute_extensions: HashMap<String, AttributeExtension>,
+    found_entries: Vec<String>,
+}
+
+impl OptionIdTrackingValidation {
+    /// # Arguments
+    ///
+    /// * `entries` - tracked options, with the option's id as key.
+    /// * `attribute_extensions` - extension of each attribute that has any, with the attribute's id as key.
+    pub fn new(
+        entries: &HashMap<String, OptionEntry>,
+        attribute_extensions: HashMap<String, AttributeExtension>,
+    ) -> OptionIdTrackingValidation {
+        OptionIdTrackingValidation {
+            entries: HashMap::clone(entries),
+            attribute_extensions,
+            found_entries: Vec::new(),
+        }
+    }
+}
+
+impl Validation for OptionIdTrackingValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let options = match self.attribute_extensions.get(&attribute.id) {
+                Some(attribute_extension) => attribute_extension.options.as_slice(),
+                None => continue,
+            };
+
+            for option in options {
+                // Options without id are given one while loading.
+                let id = match &option.id {
+                    Some(id) => id,
+                    None => continue,
+                };
+
+                let (kind, message, details) = match self.entries.remove(id) {
+                    Some(entry) => {
+                        self.found_entries.push(id.clone());
+
+                        let message = if entry.attribute_id != attribute.id {
+                            format!(
+                                "option '{}' with id '{}' of attribute '{}' has been published for the attribute with id '{}'",
+                                option.name, id, attribute.name, entry.attribute_id
+                            )
+                        } else if entry.name != option.name {
+                            format!(
+                                "option with id '{}' of attribute '{}' has been published as '{}', not as '{}'",
+                                id, attribute.name, entry.name, option.name
+                            )
+                        } else {
+                            continue;
+                        };
+
+                        (
+                            ErrorKind::OptionReused,
+                            message,
+                            ErrorDetails::Option {
+                                id: entry.id,
+                                attribute_id: entry.attribute_id,
+                                name: entry.name,
+                            },
+                        )
+                    }
+                    None if self.found_entries.contains(id) => (
+                        ErrorKind::DuplicatedOptionId,
+                        format!("duplicated option id {}", id),
+                        ErrorDetails::Id { id: id.clone() },
+                    ),
+                    None => (
+                        ErrorKind::OptionIdNotTracked,
+                        format!("option with id {} does not exist", id),
+                        ErrorDetails::Id { id: id.clone() },
+                    ),
+                };
+
+                diagnostics.push(
+                    Diagnostic::for_attribute(kind, message.as_str(), category, attribute)
+                        .with_details(Some(details)),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, diagnostics: &mut Diagnostics) -> Result<(), Error> {
+        let mut removed_entries: Vec<&OptionEntry> = self.entries.values().collect();
+        removed_entries.sort_by(|first, second| first.id.cmp(&second.id));
+
+        for entry in removed_entries {
+            diagnostics.push(Diagnostic::from(
+                Error::new(
+                    ErrorKind::OptionRemoved,
+                    format!(
+                        "option '{}' with id '{}' of the attribute with id '{}' has been removed",
+                        entry.name, entry.id, entry.attribute_id
+                    )
+                    .as_str(),
+                )
+                .with_details(ErrorDetails::Option {
+                    id: entry.id.clone(),
+                    attribute_id: entry.attribute_id.clone(),
+                    name: entry.name.clone(),
+                }),
+            ));
+        }
+
+        Ok(())
+    }
+}
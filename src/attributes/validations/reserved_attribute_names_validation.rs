@@ -1,40 +1,55 @@
-use cooplan_definitions_lib::attribute::Attribute;
+use cooplan_definitions_lib::category::Category;
 
-use crate::error::{Error, ErrorKind};
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::reserved_keywords::{describe_reserved_keyword, ReservedKeywords};
 
 use super::validation::Validation;
 
 pub struct ReservedAttributeNamesValidation {
-    reserved_keywords: Vec<String>,
+    reserved_keywords: ReservedKeywords,
 }
 
 impl ReservedAttributeNamesValidation {
-    pub fn new(reserved_keywords: Vec<String>) -> ReservedAttributeNamesValidation {
-        ReservedAttributeNamesValidation { reserved_keywords }
+    pub fn new(reserved_keywords: impl Into<ReservedKeywords>) -> ReservedAttributeNamesValidation {
+        ReservedAttributeNamesValidation {
+            reserved_keywords: reserved_keywords.into(),
+        }
     }
 }
 
 impl Validation for ReservedAttributeNamesValidation {
-    fn partially_validate(&mut self, attributes: &[Attribute]) -> Result<(), Error> {
-        for attribute in attributes {
-            for reserved_keyword in self.reserved_keywords.as_slice() {
-                if attribute.name.to_lowercase().eq(reserved_keyword) {
-                    return Err(Error::new(
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            if let Some(reserved_keyword) = self.reserved_keywords.find(attribute.name.as_str()) {
+                diagnostics.push(
+                    Diagnostic::for_attribute(
                         ErrorKind::ReservedKeywordUsedAsName,
                         format!(
                             "attribute with id '{}' is using as name a reserved keyword: {}",
-                            attribute.id, reserved_keyword
+                            attribute.id,
+                            describe_reserved_keyword(reserved_keyword)
                         )
                         .as_str(),
-                    ));
-                }
+                        category,
+                        attribute,
+                    )
+                    .with_details(Some(ErrorDetails::ReservedKeyword {
+                        keyword: reserved_keyword.keyword().to_string(),
+                        explanation: reserved_keyword.explanation().map(str::to_string),
+                    })),
+                );
             }
         }
 
         Ok(())
     }
 
-    fn complete(&mut self) -> Result<(), Error> {
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
         // This validation does not require a completion call. Therefore, in the end we are always ok :)
         Ok(())
     }
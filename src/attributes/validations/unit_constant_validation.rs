@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::{
+    attributes::attribute_tracker_io::AttributeEntry,
+    diagnostics::{Diagnostic, Diagnostics},
+    error::{Error, ErrorDetails, ErrorKind},
+};
+
+use super::validation::Validation;
+
+/// Validates whether an attribute's unit has changed since it was tracked.
+///
+/// An empty unit is the same as no unit. Attributes whose entries predate the tracking of units are not
+/// validated.
+pub struct UnitConstantValidation {
+    expected_units: HashMap<String, Option<String>>,
+}
+
+impl UnitConstantValidation {
+    pub fn new(entries: &HashMap<String, AttributeEntry>) -> UnitConstantValidation {
+        let mut expected_units: HashMap<String, Option<String>> = HashMap::new();
+
+        for entry in entries.values() {
+            match entry.unit.as_deref() {
+                Some("") => expected_units.insert(entry.id.clone(), None),
+                Some(unit) => expected_units.insert(entry.id.clone(), Some(unit.to_string())),
+                None => continue,
+            };
+        }
+
+        UnitConstantValidation { expected_units }
+    }
+}
+
+impl Validation for UnitConstantValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            if let Some(expected_unit) = self.expected_units.remove(&attribute.id) {
+                let unit = attribute.unit.clone().filter(|unit| !unit.is_empty());
+
+                if expected_unit != unit {
+                    let from = expected_unit.unwrap_or_default();
+                    let to = unit.unwrap_or_default();
+
+                    diagnostics.push(
+                        Diagnostic::for_attribute(
+                            ErrorKind::UnitChanged,
+                            format!(
+                                "attribute '{}' with id '{}' changed its unit from '{}' to '{}'",
+                                attribute.name, attribute.id, from, to
+                            )
+                            .as_str(),
+                            category,
+                            attribute,
+                        )
+                        .with_details(Some(ErrorDetails::UnitChange { from, to })),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
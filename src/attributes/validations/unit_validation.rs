@@ -0,0 +1,72 @@
+use cooplan_definitions_lib::category::Category;
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::type_system::data_type_name;
+use crate::unit_catalog::UnitCatalog;
+
+use super::validation::Validation;
+
+/// Validates that units are only set for the data types that accept them, and belong to their catalog.
+///
+/// An empty unit is the same as no unit.
+pub struct UnitValidation {
+    unit_catalog: UnitCatalog,
+}
+
+impl UnitValidation {
+    pub fn new(unit_catalog: UnitCatalog) -> UnitValidation {
+        UnitValidation { unit_catalog }
+    }
+}
+
+impl Validation for UnitValidation {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for attribute in category.attributes.as_slice() {
+            let unit = match attribute.unit.as_deref() {
+                Some("") | None => continue,
+                Some(unit) => unit,
+            };
+
+            // Parameters do not matter, e.g. `decimal(precision=10,scale=2)` accepts the units of `decimal`.
+            let data_type_name = data_type_name(attribute.data_type.as_str());
+
+            let (kind, message) = if !self.unit_catalog.accepts_units(&data_type_name) {
+                (
+                    ErrorKind::UnitNotAllowed,
+                    format!(
+                        "attribute '{}' with id '{}' has the unit '{}', while its data type '{}' does not accept units",
+                        attribute.name, attribute.id, unit, attribute.data_type
+                    ),
+                )
+            } else if !self.unit_catalog.contains(&data_type_name, unit) {
+                (
+                    ErrorKind::UnknownUnit,
+                    format!(
+                        "attribute '{}' with id '{}' has the unit '{}', which is not within the catalog of data type '{}'",
+                        attribute.name, attribute.id, unit, attribute.data_type
+                    ),
+                )
+            } else {
+                continue;
+            };
+
+            diagnostics.push(
+                Diagnostic::for_attribute(kind, message.as_str(), category, attribute)
+                    .with_details(Some(ErrorDetails::Unit {
+                        unit: unit.to_string(),
+                    })),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn complete(&mut self, _: &mut Diagnostics) -> Result<(), Error> {
+        Ok(())
+    }
+}
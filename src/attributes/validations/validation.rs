@@ -1,8 +1,18 @@
-use cooplan_definitions_lib::attribute::Attribute;
+use cooplan_definitions_lib::category::Category;
 
+use crate::diagnostics::Diagnostics;
 use crate::error::Error;
 
 pub trait Validation {
-    fn partially_validate(&mut self, attributes: &[Attribute]) -> Result<(), Error>;
-    fn complete(&mut self) -> Result<(), Error>;
+    /// Validates the attributes of the category, pushing every problem found into the diagnostics.
+    ///
+    /// `Err` is only returned if the validation could not be completed.
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error>;
+
+    /// To be called after the attributes of all categories have been partially validated.
+    fn complete(&mut self, diagnostics: &mut Diagnostics) -> Result<(), Error>;
 }
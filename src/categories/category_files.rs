@@ -0,0 +1,83 @@
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use crate::glob::glob_matches;
+
+/// Extension, including its leading dot, of the files that define a category.
+pub const CATEGORY_FILE_EXTENSION: &str = ".json";
+
+/// Finds the path of each file, within the directory and its subdirectories, that has been found to be a
+/// category definition.
+///
+/// # Returns
+///
+/// `Ok`: paths of the category definition files, the ones of each directory before the ones of its subdirectories.
+/// `Err`: error detailing why the function has failed.
+pub fn find_category_files(directory: &str) -> Result<Vec<String>, Error> {
+    let mut category_files: Vec<String> = Vec::new();
+    let mut directories: Vec<String> = Vec::new();
+
+    match std::fs::read_dir(directory) {
+        Ok(read) => {
+            for entry_result in read {
+                match entry_result {
+                    Ok(entry) => {
+                        let path = match entry.path().to_str() {
+                            Some(path) => path.to_string(),
+                            None => {
+                                return Err(Error::new(
+                                    ErrorKind::Unsupported,
+                                    "could not convert path to string.",
+                                ))
+                            }
+                        };
+
+                        match entry.file_type() {
+                            Ok(file_type) => {
+                                if file_type.is_dir() {
+                                    directories.push(path);
+                                } else if path.ends_with(CATEGORY_FILE_EXTENSION) {
+                                    category_files.push(path);
+                                }
+                            }
+                            Err(error) => return Err(error),
+                        }
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+        Err(error) => return Err(error),
+    }
+
+    category_files.sort();
+    directories.sort();
+
+    for directory in directories {
+        match find_category_files(directory.as_str()) {
+            Ok(mut child_category_files) => category_files.append(&mut child_category_files),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(category_files)
+}
+
+/// Whether the file, or directory, matches any of the globs of the allowed files, relative to the
+/// categories' root directory.
+pub fn is_allowed_file(categories_root: &str, path: &str, allowed_files: &[String]) -> bool {
+    let relative_path = match Path::new(path).strip_prefix(categories_root) {
+        Ok(relative_path) => relative_path,
+        Err(_) => Path::new(path),
+    };
+
+    let relative_path = relative_path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<String>>()
+        .join("/");
+
+    allowed_files
+        .iter()
+        .any(|allowed_file| glob_matches(allowed_file.as_str(), relative_path.as_str()))
+}
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
-use crate::error::{Error, ErrorKind};
+use crate::error::{Error, ErrorDetails, ErrorKind};
 
+#[derive(Clone)]
 pub struct CategoryEntry {
     pub id: String,
 }
@@ -39,13 +40,15 @@ impl CategoryIdTracker {
                     return Err(Error::new(
                         ErrorKind::DuplicatedId,
                         format!("duplicated category id {}", id).as_str(),
-                    ));
+                    )
+                    .with_details(ErrorDetails::Id { id: id.to_string() }));
                 }
 
                 Err(Error::new(
                     ErrorKind::IdNotFound,
                     format!("category id {} does not exist", id).as_str(),
-                ))
+                )
+                .with_details(ErrorDetails::Id { id: id.to_string() }))
             }
         }
     }
@@ -60,17 +63,17 @@ impl CategoryIdTracker {
             return Ok(());
         }
 
-        let mut missing_ids: String = "".to_string();
+        let mut missing_ids: Vec<String> = self.entries.keys().cloned().collect();
+        missing_ids.sort();
 
-        for (id, _) in self.entries.iter() {
-            missing_ids.push_str(&format!("{}, ", id));
-        }
-
-        missing_ids = missing_ids.trim_end_matches(", ").to_string();
-
-        return Err(Error::new(
+        Err(Error::new(
             ErrorKind::IdNotTracked,
-            format!("Some category ids were not tracked: {}", missing_ids).as_str(),
-        ));
+            format!(
+                "Some category ids were not tracked: {}",
+                missing_ids.join(", ")
+            )
+            .as_str(),
+        )
+        .with_details(ErrorDetails::Ids { ids: missing_ids }))
     }
 }
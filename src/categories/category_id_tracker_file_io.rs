@@ -4,13 +4,15 @@ use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
 
 use super::category_id_tracker::CategoryEntry;
 
-const ID_TRACKER_PATH: &str = "./category_id_tracker.csv";
-
-pub struct CategoryIdTrackerFileIO {}
+pub struct CategoryIdTrackerFileIO {
+    path: String,
+}
 
 impl CategoryIdTrackerFileIO {
-    pub fn new() -> CategoryIdTrackerFileIO {
-        CategoryIdTrackerFileIO {}
+    pub fn new(path: &str) -> CategoryIdTrackerFileIO {
+        CategoryIdTrackerFileIO {
+            path: path.to_string(),
+        }
     }
 }
 
@@ -18,7 +20,7 @@ impl CategoryIdTrackerIO for CategoryIdTrackerFileIO {
     fn read_entries(&self) -> Result<HashMap<String, CategoryEntry>, Error> {
         let mut entries: HashMap<String, CategoryEntry> = HashMap::new();
 
-        match std::fs::read_to_string(ID_TRACKER_PATH) {
+        match std::fs::read_to_string(self.path.as_str()) {
             Ok(content) => {
                 for id in content.lines() {
                     if id.is_empty() {
@@ -37,11 +39,11 @@ impl CategoryIdTrackerIO for CategoryIdTrackerFileIO {
     }
 
     fn write_entry(&self, entry: &CategoryEntry) -> Result<(), Error> {
-        match std::fs::read_to_string(ID_TRACKER_PATH) {
+        match std::fs::read_to_string(self.path.as_str()) {
             Ok(current_content) => {
                 let new_content = format!("{}\n{}", current_content, entry.id);
 
-                match std::fs::write(ID_TRACKER_PATH, new_content) {
+                match std::fs::write(self.path.as_str(), new_content) {
                     Ok(_) => Ok(()),
                     Err(error) => Err(error),
                 }
@@ -0,0 +1,32 @@
+use std::{cell::RefCell, collections::HashMap, io::Error};
+
+use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
+
+use super::category_id_tracker::CategoryEntry;
+
+/// In-memory category ID tracking storage, used for running the CI logic without writing any file.
+pub struct CategoryIdTrackerMemoryIO {
+    entries: RefCell<HashMap<String, CategoryEntry>>,
+}
+
+impl CategoryIdTrackerMemoryIO {
+    pub fn new(entries: HashMap<String, CategoryEntry>) -> CategoryIdTrackerMemoryIO {
+        CategoryIdTrackerMemoryIO {
+            entries: RefCell::new(entries),
+        }
+    }
+}
+
+impl CategoryIdTrackerIO for CategoryIdTrackerMemoryIO {
+    fn read_entries(&self) -> Result<HashMap<String, CategoryEntry>, Error> {
+        Ok(self.entries.borrow().clone())
+    }
+
+    fn write_entry(&self, entry: &CategoryEntry) -> Result<(), Error> {
+        self.entries
+            .borrow_mut()
+            .insert(entry.id.clone(), entry.clone());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+/// Category whose parent has been resolved, before it is placed within the tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParentReference {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+}
+
+/// Order in which categories can be placed within their tree, alongside the categories that cannot be
+/// placed at all. Categories are referred to by their index within the sorted slice.
+#[derive(Debug, PartialEq)]
+pub struct CategoryTreeOrder {
+    /// Categories that can be placed, every parent before its children.
+    pub sorted: Vec<usize>,
+    /// Categories whose parent is not among the categories.
+    pub missing_parents: Vec<usize>,
+    /// Categories of each cycle, in the order their parent references are followed.
+    pub cycles: Vec<Vec<usize>>,
+}
+
+/// Sorts the categories topologically by their parent, regardless of the order they are given in.
+///
+/// Root categories and siblings keep their relative order. Descendants of categories that cannot be
+/// placed, i.e. whose parent is missing or that are part of a cycle, are not placed either.
+///
+/// # Arguments
+///
+/// * `categories` - categories alongside the id of their parent, if any.
+pub fn sort_by_parent(categories: &[ParentReference]) -> CategoryTreeOrder {
+    let mut indexes: HashMap<&str, usize> = HashMap::new();
+
+    for (index, category) in categories.iter().enumerate() {
+        indexes.entry(category.id.as_str()).or_insert(index);
+    }
+
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); categories.len()];
+    let mut roots: Vec<usize> = Vec::new();
+    let mut missing_parents: Vec<usize> = Vec::new();
+
+    for (index, category) in categories.iter().enumerate() {
+        match &category.parent_id {
+            Some(parent_id) => match indexes.get(parent_id.as_str()) {
+                Some(parent_index) => children[*parent_index].push(index),
+                None => missing_parents.push(index),
+            },
+            None => roots.push(index),
+        }
+    }
+
+    let mut placed = vec![false; categories.len()];
+    let mut sorted: Vec<usize> = Vec::new();
+    let mut next = 0;
+    sorted.extend(roots.iter());
+
+    for root in roots {
+        placed[root] = true;
+    }
+
+    while next < sorted.len() {
+        let index = sorted[next];
+        next += 1;
+
+        for child in children[index].as_slice() {
+            if !placed[*child] {
+                placed[*child] = true;
+                sorted.push(*child);
+            }
+        }
+    }
+
+    CategoryTreeOrder {
+        sorted,
+        cycles: find_cycles(categories, &indexes, &placed),
+        missing_parents,
+    }
+}
+
+/// Follows the parent references of the categories that have not been placed, finding every cycle.
+fn find_cycles(
+    categories: &[ParentReference],
+    indexes: &HashMap<&str, usize>,
+    placed: &[bool],
+) -> Vec<Vec<usize>> {
+    let mut visited = vec![false; categories.len()];
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+
+    for start in 0..categories.len() {
+        if placed[start] || visited[start] {
+            continue;
+        }
+
+        let mut path: Vec<usize> = Vec::new();
+        let mut current = Some(start);
+
+        while let Some(index) = current {
+            if visited[index] {
+                if let Some(position) = path.iter().position(|path_index| *path_index == index) {
+                    cycles.push(path[position..].to_vec());
+                }
+
+                break;
+            }
+
+            visited[index] = true;
+            path.push(index);
+
+            current = match &categories[index].parent_id {
+                Some(parent_id) => indexes.get(parent_id.as_str()).copied(),
+                None => None,
+            };
+        }
+    }
+
+    cycles
+}
@@ -1,5 +1,8 @@
+pub mod category_files;
 pub mod category_id_generator;
 pub mod category_id_tracker;
 pub mod category_id_tracker_file_io;
 pub mod category_id_tracker_io;
+pub mod category_id_tracker_memory_io;
+pub mod category_tree_order;
 pub mod validations;
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::categories::category_tree_order::ParentReference;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{ErrorDetails, ErrorKind};
+
+/// Validates that no category is its own ancestor, i.e. that following the parents of a category never
+/// leads back to it.
+///
+/// Given the tree cannot be built out of such categories, this validation runs on the cycles found while
+/// sorting the categories' parent references, before the tree is built. Every category of each cycle is
+/// reported, alongside the whole cycle.
+pub struct CategoryCycleValidation {
+    categories_path: HashMap<String, String>,
+}
+
+impl CategoryCycleValidation {
+    /// # Arguments
+    ///
+    /// * `categories_path` - path of each category's file, with the category's id as key.
+    pub fn new(categories_path: HashMap<String, String>) -> CategoryCycleValidation {
+        CategoryCycleValidation { categories_path }
+    }
+
+    /// Description of the cycle, following the parents back to the first category, e.g.
+    /// `'a' (a-id, b/a.json) -> 'b' (b-id, a/b.json) -> 'a'`.
+    fn describe_cycle(&self, members: &[&ParentReference]) -> String {
+        let mut steps: Vec<String> = members
+            .iter()
+            .map(|member| match self.categories_path.get(&member.id) {
+                Some(path) => format!("'{}' ({}, {})", member.name, member.id, path),
+                None => format!("'{}' ({})", member.name, member.id),
+            })
+            .collect();
+
+        steps.push(format!("'{}'", members[0].name));
+
+        steps.join(" -> ")
+    }
+
+    /// Pushes a diagnostic for every category of each cycle into the diagnostics.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - parent references of the categories.
+    /// * `cycles` - cycles found by `sort_by_parent`, as indexes of `categories`.
+    pub fn validate(
+        &self,
+        categories: &[ParentReference],
+        cycles: &[Vec<usize>],
+        diagnostics: &mut Diagnostics,
+    ) {
+        for cycle in cycles {
+            let members: Vec<&ParentReference> =
+                cycle.iter().map(|index| &categories[*index]).collect();
+            let ids: Vec<String> = members.iter().map(|member| member.id.clone()).collect();
+            let description = self.describe_cycle(members.as_slice());
+
+            for member in members {
+                let mut diagnostic = Diagnostic::new(
+                    ErrorKind::CategoryCycle,
+                    format!(
+                        "category '{}' is its own ancestor through its parents: {}",
+                        member.name, description
+                    )
+                    .as_str(),
+                )
+                .with_details(Some(ErrorDetails::Ids { ids: ids.clone() }));
+
+                diagnostic.category_id = Some(member.id.clone());
+                diagnostic.category_name = Some(member.name.clone());
+
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::category_files::{is_allowed_file, CATEGORY_FILE_EXTENSION};
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorKind};
+use crate::source_location::SourceLocation;
+
+/// Validates that the categories' root directory only contains category definitions:
+///
+/// * Files that are not JSON files.
+/// * Directories without any file, reporting the outermost one.
+///
+/// JSON files that are not valid category definitions are reported while loading the categories.
+///
+/// Files and directories that match any of the allowed files' globs, relative to the categories' root
+/// directory, are not reported.
+pub struct CategoryFilesValidation {
+    categories_root: String,
+    allowed_files: Vec<String>,
+}
+
+impl CategoryFilesValidation {
+    /// # Arguments
+    ///
+    /// * `categories_root` - root directory of the categories.
+    /// * `allowed_files` - globs of the files that are allowed besides the category definitions.
+    pub fn new(categories_root: &str, allowed_files: Vec<String>) -> CategoryFilesValidation {
+        CategoryFilesValidation {
+            categories_root: categories_root.to_string(),
+            allowed_files,
+        }
+    }
+
+    /// Validates the files within the directory and its subdirectories.
+    ///
+    /// # Returns
+    ///
+    /// `Ok`: whether the directory, or any of its subdirectories, contains any file.
+    /// `Err`: error detailing why the directory could not be read.
+    fn validate_directory(
+        &self,
+        directory: &str,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<bool, Error> {
+        let mut files: Vec<String> = Vec::new();
+        let mut directories: Vec<String> = Vec::new();
+
+        match std::fs::read_dir(directory) {
+            Ok(read) => {
+                for entry_result in read {
+                    match entry_result {
+                        Ok(entry) => {
+                            let path = entry.path().to_string_lossy().to_string();
+
+                            match entry.file_type() {
+                                Ok(file_type) => {
+                                    if file_type.is_dir() {
+                                        directories.push(path);
+                                    } else {
+                                        files.push(path);
+                                    }
+                                }
+                                Err(error) => return Err(failed_to_read(directory, error)),
+                            }
+                        }
+                        Err(error) => return Err(failed_to_read(directory, error)),
+                    }
+                }
+            }
+            Err(error) => return Err(failed_to_read(directory, error)),
+        }
+
+        files.sort();
+        directories.sort();
+
+        for file in files.as_slice() {
+            if !self.is_allowed(file) {
+                self.validate_file(file, diagnostics);
+            }
+        }
+
+        let mut empty_directories: Vec<String> = Vec::new();
+
+        for child_directory in directories.as_slice() {
+            let mut child_diagnostics = Diagnostics::new();
+
+            match self.validate_directory(child_directory, &mut child_diagnostics) {
+                Ok(true) => diagnostics.append(child_diagnostics),
+                Ok(false) => {
+                    if !self.is_allowed(child_directory) {
+                        empty_directories.push(child_directory.clone());
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        let has_files = !files.is_empty() || empty_directories.len() < directories.len();
+
+        // Empty directories are reported by the outermost directory without files.
+        if has_files || directory == self.categories_root {
+            for empty_directory in empty_directories {
+                let mut diagnostic = Diagnostic::new(
+                    ErrorKind::EmptyDirectory,
+                    format!("directory '{}' does not contain any file", empty_directory).as_str(),
+                );
+                diagnostic.set_location(SourceLocation::new(empty_directory.as_str()));
+
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        Ok(has_files)
+    }
+
+    fn validate_file(&self, file: &str, diagnostics: &mut Diagnostics) {
+        if !file.ends_with(CATEGORY_FILE_EXTENSION) {
+            let mut diagnostic = Diagnostic::new(
+                ErrorKind::StrayFile,
+                format!(
+                    "file '{}' is not a category definition, given it is not a JSON file",
+                    file
+                )
+                .as_str(),
+            );
+            diagnostic.set_location(SourceLocation::new(file));
+
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    fn is_allowed(&self, path: &str) -> bool {
+        is_allowed_file(
+            self.categories_root.as_str(),
+            path,
+            self.allowed_files.as_slice(),
+        )
+    }
+}
+
+impl Validation for CategoryFilesValidation {
+    fn validate(
+        &self,
+        _: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        match self.validate_directory(self.categories_root.as_str(), diagnostics) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+fn failed_to_read(directory: &str, error: std::io::Error) -> Error {
+    Error::new(
+        ErrorKind::FailedToReadCategory,
+        format!("failed to read directory '{}'", directory).as_str(),
+    )
+    .with_source(error)
+}
@@ -0,0 +1,91 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::naming_convention::{NamingConvention, NamingRules};
+
+/// Validates that category names follow the naming convention set within the configuration, if any.
+pub struct CategoryNamingConventionValidation {
+    rules: Result<Option<NamingRules>, Error>,
+}
+
+impl CategoryNamingConventionValidation {
+    pub fn new(convention: Option<NamingConvention>) -> CategoryNamingConventionValidation {
+        let rules = match convention {
+            Some(convention) => NamingRules::try_new(&convention).map(Some),
+            None => Ok(None),
+        };
+
+        CategoryNamingConventionValidation { rules }
+    }
+
+    fn validate_category(
+        &self,
+        rules: &NamingRules,
+        category_pointer: &Rc<RefCell<Category>>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        match category_pointer.try_borrow() {
+            Ok(category) => {
+                let violations = rules.violations(category.name.as_str());
+
+                if !violations.is_empty() {
+                    diagnostics.push(
+                        Diagnostic::for_category(
+                            ErrorKind::NamingConventionViolated,
+                            format!(
+                                "category name '{}' {}",
+                                category.name,
+                                violations.join(", ")
+                            )
+                            .as_str(),
+                            &category,
+                        )
+                        .with_details(Some(ErrorDetails::NamingConvention { violations })),
+                    );
+                }
+
+                for child in category.children.as_slice() {
+                    match self.validate_category(rules, child, diagnostics) {
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
+                    }
+                }
+
+                Ok(())
+            }
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToBorrowCategory,
+                "failed to borrow category",
+            )
+            .with_source(error)),
+        }
+    }
+}
+
+impl Validation for CategoryNamingConventionValidation {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let rules = match &self.rules {
+            Ok(Some(rules)) => rules,
+            Ok(None) => return Ok(()),
+            Err(error) => return Err(Error::new(error.kind(), error.full_message().as_str())),
+        };
+
+        for root_category in root_categories {
+            match self.validate_category(rules, root_category, diagnostics) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::category_files::CATEGORY_FILE_EXTENSION;
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+
+/// Validates that the directory layout mirrors the category tree, i.e. that each category's file is
+/// named after the category and lives within the directory named after its parent, e.g.
+/// `categories/food/fruit/pear.json`.
+pub struct DirectoryLayoutValidation {
+    categories_root: String,
+    categories_path: HashMap<String, String>,
+}
+
+impl DirectoryLayoutValidation {
+    /// # Arguments
+    ///
+    /// * `categories_root` - root directory of the categories.
+    /// * `categories_path` - path of each category's file, with the category's id as key.
+    pub fn new(
+        categories_root: &str,
+        categories_path: HashMap<String, String>,
+    ) -> DirectoryLayoutValidation {
+        DirectoryLayoutValidation {
+            categories_root: categories_root.to_string(),
+            categories_path,
+        }
+    }
+
+    fn validate_category(
+        &self,
+        category_pointer: &Rc<RefCell<Category>>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        match category_pointer.try_borrow() {
+            Ok(category) => {
+                if let Some(path) = self.categories_path.get(&category.id) {
+                    match expected_category_path(self.categories_root.as_str(), category_pointer) {
+                        Ok(expected_path) if Path::new(path) != expected_path => {
+                            let expected_path = expected_path.to_string_lossy().to_string();
+
+                            diagnostics.push(
+                                Diagnostic::for_category(
+                                    ErrorKind::MisplacedCategoryFile,
+                                    format!(
+                                        "category '{}' is defined in '{}', while its parents place it in '{}'",
+                                        category.name, path, expected_path
+                                    )
+                                    .as_str(),
+                                    &category,
+                                )
+                                .with_details(Some(ErrorDetails::ExpectedPath {
+                                    path: expected_path,
+                                })),
+                            );
+                        }
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
+                    }
+                }
+
+                for child in category.children.as_slice() {
+                    match self.validate_category(child, diagnostics) {
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
+                    }
+                }
+
+                Ok(())
+            }
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToBorrowCategory,
+                "failed to borrow category",
+            )
+            .with_source(error)),
+        }
+    }
+}
+
+impl Validation for DirectoryLayoutValidation {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for root_category in root_categories {
+            match self.validate_category(root_category, diagnostics) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Path where the category's file is expected to be: within the directories named after its ancestors,
+/// the root category's first, and named after the category.
+pub fn expected_category_path(
+    categories_root: &str,
+    category_pointer: &Rc<RefCell<Category>>,
+) -> Result<PathBuf, Error> {
+    let mut names: Vec<String> = Vec::new();
+    let mut current = Some(Rc::clone(category_pointer));
+
+    while let Some(pointer) = current {
+        match pointer.try_borrow() {
+            Ok(category) => {
+                names.push(category.name.clone());
+                current = category.parent.as_ref().and_then(|parent| parent.upgrade());
+            }
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToBorrowCategory,
+                    "failed to borrow category",
+                )
+                .with_source(error))
+            }
+        }
+    }
+
+    let mut path = PathBuf::from(categories_root);
+    let file_name = names.remove(0);
+
+    for name in names.iter().rev() {
+        path.push(name);
+    }
+
+    path.push(file_name);
+    path.set_extension(CATEGORY_FILE_EXTENSION.trim_start_matches('.'));
+
+    Ok(path)
+}
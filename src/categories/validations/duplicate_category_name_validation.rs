@@ -0,0 +1,236 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+
+/// Category alongside its id and name, so they can be compared without borrowing it again.
+struct NamedCategory {
+    id: String,
+    name: String,
+    pointer: Rc<RefCell<Category>>,
+}
+
+/// Validates that category names are unique, given categories are linked with their parents by name.
+///
+/// * Globally - no two categories share the same name.
+/// * Among siblings - no two children of the same parent have names that only differ in case.
+///
+/// Every category involved is reported, alongside the ids and files of the others.
+pub struct DuplicateCategoryNameValidation {
+    categories_path: HashMap<String, String>,
+}
+
+impl DuplicateCategoryNameValidation {
+    /// # Arguments
+    ///
+    /// * `categories_path` - path of each category's file, with the category's id as key.
+    pub fn new(categories_path: HashMap<String, String>) -> DuplicateCategoryNameValidation {
+        DuplicateCategoryNameValidation { categories_path }
+    }
+
+    /// Validates the names among the siblings and collects them, alongside their descendants.
+    fn validate_siblings(
+        &self,
+        parent_name: Option<&str>,
+        siblings: &[Rc<RefCell<Category>>],
+        categories: &mut Vec<NamedCategory>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let mut sibling_groups: Vec<(String, Vec<NamedCategory>)> = Vec::new();
+
+        for sibling_pointer in siblings {
+            let (id, name) = match sibling_pointer.try_borrow() {
+                Ok(sibling) => (sibling.id.clone(), sibling.name.clone()),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowCategory,
+                        "failed to borrow category",
+                    )
+                    .with_source(error))
+                }
+            };
+
+            let normalized_name = name.to_lowercase();
+            let member = NamedCategory {
+                id: id.clone(),
+                name: name.clone(),
+                pointer: Rc::clone(sibling_pointer),
+            };
+
+            match sibling_groups
+                .iter_mut()
+                .find(|(group_name, _)| *group_name == normalized_name)
+            {
+                Some((_, members)) => members.push(member),
+                None => sibling_groups.push((normalized_name, vec![member])),
+            }
+
+            categories.push(NamedCategory {
+                id,
+                name,
+                pointer: Rc::clone(sibling_pointer),
+            });
+        }
+
+        for (normalized_name, members) in sibling_groups {
+            // Siblings sharing the very same name are reported as global duplicates.
+            if members.len() < 2 || members.iter().all(|member| member.name == members[0].name) {
+                continue;
+            }
+
+            let names: Vec<String> = members
+                .iter()
+                .map(|member| format!("'{}'", member.name))
+                .collect();
+            let siblings_description = match parent_name {
+                Some(parent_name) => format!("children of '{}'", parent_name),
+                None => "root categories".to_string(),
+            };
+
+            match self.report(
+                ErrorKind::DuplicatedSiblingName,
+                format!(
+                    "{} have names that only differ in case, {}: {}",
+                    siblings_description,
+                    names.join(", "),
+                    self.describe(members.as_slice())
+                )
+                .as_str(),
+                normalized_name.as_str(),
+                members.as_slice(),
+                diagnostics,
+            ) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        for sibling_pointer in siblings {
+            match sibling_pointer.try_borrow() {
+                Ok(sibling) => {
+                    match self.validate_siblings(
+                        Some(sibling.name.as_str()),
+                        sibling.children.as_slice(),
+                        categories,
+                        diagnostics,
+                    ) {
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
+                    }
+                }
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowCategory,
+                        "failed to borrow category",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ids of the categories, followed by the file each one is defined in, if known.
+    fn describe(&self, members: &[NamedCategory]) -> String {
+        members
+            .iter()
+            .map(|member| match self.categories_path.get(&member.id) {
+                Some(path) => format!("{} ({})", member.id, path),
+                None => member.id.clone(),
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Pushes a diagnostic for each category involved, so each file is pointed at.
+    fn report(
+        &self,
+        kind: ErrorKind,
+        message: &str,
+        name: &str,
+        members: &[NamedCategory],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let ids: Vec<String> = members.iter().map(|member| member.id.clone()).collect();
+
+        for member in members {
+            match member.pointer.try_borrow() {
+                Ok(category) => diagnostics.push(
+                    Diagnostic::for_category(kind, message, &category).with_details(Some(
+                        ErrorDetails::Name {
+                            name: name.to_string(),
+                            ids: ids.clone(),
+                        },
+                    )),
+                ),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowCategory,
+                        "failed to borrow category",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validation for DuplicateCategoryNameValidation {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let mut categories: Vec<NamedCategory> = Vec::new();
+
+        match self.validate_siblings(None, root_categories, &mut categories, diagnostics) {
+            Ok(_) => (),
+            Err(error) => return Err(error),
+        }
+
+        let mut name_groups: Vec<(String, Vec<NamedCategory>)> = Vec::new();
+
+        for category in categories {
+            match name_groups
+                .iter_mut()
+                .find(|(group_name, _)| *group_name == category.name)
+            {
+                Some((_, members)) => members.push(category),
+                None => name_groups.push((category.name.clone(), vec![category])),
+            }
+        }
+
+        for (name, members) in name_groups {
+            if members.len() < 2 {
+                continue;
+            }
+
+            match self.report(
+                ErrorKind::DuplicatedName,
+                format!(
+                    "category name '{}' is used by {} categories: {}",
+                    name,
+                    members.len(),
+                    self.describe(members.as_slice())
+                )
+                .as_str(),
+                name.as_str(),
+                members.as_slice(),
+                diagnostics,
+            ) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
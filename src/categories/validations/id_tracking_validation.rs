@@ -4,73 +4,90 @@ use std::rc::Rc;
 use cooplan_definitions_lib::category::Category;
 
 use crate::categories::category_id_tracker::CategoryIdTracker;
-use crate::categories::category_id_tracker_file_io::CategoryIdTrackerFileIO;
 use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
 use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
 use crate::error::{Error, ErrorKind};
 
 /// Validates there are no missing IDs.
-pub struct IdTrackingValidation {}
+pub struct IdTrackingValidation {
+    category_id_tracker_io: Rc<dyn CategoryIdTrackerIO>,
+}
 
 impl IdTrackingValidation {
-    pub fn new() -> IdTrackingValidation {
-        IdTrackingValidation {}
+    pub fn new(category_id_tracker_io: Rc<dyn CategoryIdTrackerIO>) -> IdTrackingValidation {
+        IdTrackingValidation {
+            category_id_tracker_io,
+        }
     }
 
     fn track_category(
         &self,
         category_pointer: &Rc<RefCell<Category>>,
         category_id_tracker: &mut CategoryIdTracker,
+        diagnostics: &mut Diagnostics,
     ) -> Result<(), Error> {
         match category_pointer.try_borrow() {
             Ok(category) => {
-                match category_id_tracker.track_category(category.id.clone().as_str()) {
-                    Ok(_) => {
-                        for child_pointer in category.children.as_slice() {
-                            match self.track_category(child_pointer, category_id_tracker) {
-                                Ok(_) => (),
-                                Err(error) => return Err(error),
-                            }
-                        }
+                match category_id_tracker.track_category(category.id.as_str()) {
+                    Ok(_) => (),
+                    Err(error) => diagnostics.push(
+                        Diagnostic::for_category(error.kind(), error.message.as_str(), &category)
+                            .with_details(error.details().cloned()),
+                    ),
+                }
 
-                        Ok(())
+                for child_pointer in category.children.as_slice() {
+                    match self.track_category(child_pointer, category_id_tracker, diagnostics) {
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
                     }
-                    Err(error) => Err(error),
                 }
+
+                Ok(())
             }
             Err(error) => Err(Error::new(
                 ErrorKind::FailedToBorrowCategory,
-                format!("failed to borrow category: {}", error).as_str(),
-            )),
+                "failed to borrow category",
+            )
+            .with_source(error)),
         }
     }
 }
 
 impl Validation for IdTrackingValidation {
-    fn validate(&self, root_categories: &[Rc<RefCell<Category>>]) -> Result<(), Error> {
-        let category_id_tracker_io: Box<dyn CategoryIdTrackerIO> =
-            Box::new(CategoryIdTrackerFileIO::new());
-
-        match category_id_tracker_io.read_entries() {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        match self.category_id_tracker_io.read_entries() {
             Ok(category_entries) => {
                 let mut category_id_tracker = CategoryIdTracker::new(category_entries);
 
                 for category_pointer in root_categories {
-                    match self.track_category(category_pointer, &mut category_id_tracker) {
+                    match self.track_category(
+                        category_pointer,
+                        &mut category_id_tracker,
+                        diagnostics,
+                    ) {
                         Ok(_) => (),
                         Err(error) => return Err(error),
                     }
                 }
 
                 match category_id_tracker.close() {
-                    Ok(_) => Ok(()),
-                    Err(error) => Err(error),
+                    Ok(_) => (),
+                    Err(error) => diagnostics.push(Diagnostic::from(error)),
                 }
+
+                Ok(())
             }
             Err(error) => Err(Error::new(
                 ErrorKind::FailedToReadCategory,
-                format!("failed to read categories: {}", error).as_str(),
-            )),
+                "failed to read categories",
+            )
+            .with_source(error)),
         }
     }
 }
@@ -1,3 +1,9 @@
-pub mod validation;
+pub mod category_cycle_validation;
+pub mod category_files_validation;
+pub mod category_naming_convention_validation;
+pub mod directory_layout_validation;
+pub mod duplicate_category_name_validation;
+pub mod id_tracking_validation;
+pub mod reserved_category_names_validation;
 pub mod selectable_as_last_validation;
-pub mod id_tracking_validation;
\ No newline at end of file
+pub mod validation;
@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::reserved_keywords::{describe_reserved_keyword, ReservedKeywords};
+
+/// Validates that no category is named after a reserved keyword.
+pub struct ReservedCategoryNamesValidation {
+    reserved_keywords: ReservedKeywords,
+}
+
+impl ReservedCategoryNamesValidation {
+    pub fn new(reserved_keywords: impl Into<ReservedKeywords>) -> ReservedCategoryNamesValidation {
+        ReservedCategoryNamesValidation {
+            reserved_keywords: reserved_keywords.into(),
+        }
+    }
+
+    fn validate_category(
+        &self,
+        category_pointer: &Rc<RefCell<Category>>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        match category_pointer.try_borrow() {
+            Ok(category) => {
+                if let Some(reserved_keyword) = self.reserved_keywords.find(category.name.as_str())
+                {
+                    diagnostics.push(
+                        Diagnostic::for_category(
+                            ErrorKind::ReservedKeywordUsedAsName,
+                            format!(
+                                "category with id '{}' is using as name a reserved keyword: {}",
+                                category.id,
+                                describe_reserved_keyword(reserved_keyword)
+                            )
+                            .as_str(),
+                            &category,
+                        )
+                        .with_details(Some(
+                            ErrorDetails::ReservedKeyword {
+                                keyword: reserved_keyword.keyword().to_string(),
+                                explanation: reserved_keyword.explanation().map(str::to_string),
+                            },
+                        )),
+                    );
+                }
+
+                for child in category.children.as_slice() {
+                    match self.validate_category(child, diagnostics) {
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
+                    }
+                }
+
+                Ok(())
+            }
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToBorrowCategory,
+                "failed to borrow category",
+            )
+            .with_source(error)),
+        }
+    }
+}
+
+impl Validation for ReservedCategoryNamesValidation {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for root_category in root_categories {
+            match self.validate_category(root_category, diagnostics) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
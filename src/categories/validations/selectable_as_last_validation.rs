@@ -4,9 +4,11 @@ use std::rc::Rc;
 use cooplan_definitions_lib::category::Category;
 
 use crate::categories::validations::validation::Validation;
+use crate::diagnostics::{Diagnostic, Diagnostics};
 use crate::error::{Error, ErrorKind};
 
 /// Validates if the each end category is set as selectable as last by itself or by its parents.
+#[derive(Default)]
 pub struct SelectableAsLastValidation {}
 
 impl SelectableAsLastValidation {
@@ -17,6 +19,7 @@ impl SelectableAsLastValidation {
     fn validate_selectable_as_last(
         &self,
         category_pointer: &Rc<RefCell<Category>>,
+        diagnostics: &mut Diagnostics,
     ) -> Result<(), Error> {
         match category_pointer.try_borrow() {
             Ok(category) => {
@@ -25,7 +28,7 @@ impl SelectableAsLastValidation {
                 }
 
                 if category.children.is_empty() {
-                    return Err(Error::new(
+                    diagnostics.push(Diagnostic::for_category(
                         ErrorKind::LastCategoryNotSelectable,
                         format!(
                             "category '{}' with id '{}' has no children and it is not selectable as last",
@@ -33,11 +36,14 @@ impl SelectableAsLastValidation {
                             category.id
                         )
                         .as_str(),
+                        &category,
                     ));
+
+                    return Ok(());
                 }
 
                 for child in category.children.as_slice() {
-                    match self.validate_selectable_as_last(child) {
+                    match self.validate_selectable_as_last(child, diagnostics) {
                         Ok(_) => (),
                         Err(error) => return Err(error),
                     }
@@ -47,16 +53,21 @@ impl SelectableAsLastValidation {
             }
             Err(error) => Err(Error::new(
                 ErrorKind::FailedToBorrowCategory,
-                format!("failed to borrow category: {}", error).as_str(),
-            )),
+                "failed to borrow category",
+            )
+            .with_source(error)),
         }
     }
 }
 
 impl Validation for SelectableAsLastValidation {
-    fn validate(&self, root_categories: &[Rc<RefCell<Category>>]) -> Result<(), Error> {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
         for category in root_categories {
-            match self.validate_selectable_as_last(category) {
+            match self.validate_selectable_as_last(category, diagnostics) {
                 Ok(_) => (),
                 Err(error) => return Err(error),
             }
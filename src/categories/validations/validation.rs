@@ -3,8 +3,19 @@ use std::rc::Rc;
 
 use cooplan_definitions_lib::category::Category;
 
+use crate::diagnostics::Diagnostics;
 use crate::error::Error;
 
 pub trait Validation {
-    fn validate(&self, root_categories: &[Rc<RefCell<Category>>]) -> Result<(), Error>;
+    /// Validates the categories, pushing every problem found into the diagnostics.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok`: the validation has been completed, even if problems have been found.
+    /// * `Err`: the validation could not be completed.
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error>;
 }
@@ -1,32 +1,70 @@
 use std::cell::{Ref, RefCell};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
+use crate::attributes::attribute_extension::{
+    merge_attribute_extensions, read_attribute_extensions, write_attribute_extensions,
+    AttributeExtension,
+};
 use crate::attributes::attribute_tracker_file_io::AttributeTrackerFileIO;
-use crate::attributes::attribute_tracker_io::{AttributeEntry, AttributeTrackerIO};
-use crate::attributes::validations::data_type_constant_validation::DataTypeConstantValidation;
-use crate::attributes::validations::data_type_validation::DataTypeValidation;
-use crate::attributes::validations::reserved_attribute_names_validation::ReservedAttributeNamesValidation;
-use cooplan_definitions_io_lib::category_file_io::build_for_all_categories;
+use crate::attributes::attribute_tracker_io::{AttributeEntry, AttributeTrackerIO, TrackedField};
+use crate::attributes::attribute_tracker_memory_io::AttributeTrackerMemoryIO;
+use crate::attributes::constraints::Constraints;
+use crate::attributes::option_tracker_file_io::OptionTrackerFileIO;
+use crate::attributes::option_tracker_io::{OptionEntry, OptionTrackerIO};
+use crate::attributes::option_tracker_memory_io::OptionTrackerMemoryIO;
+use crate::attributes::validations::validation::Validation as AttributeValidation;
+use cooplan_definitions_io_lib::category_file_io::CategoryFileIO;
 use cooplan_definitions_io_lib::category_io::CategoryIO;
+use cooplan_definitions_lib::attribute::Attribute;
 use cooplan_definitions_lib::category::Category;
 use cooplan_definitions_lib::source_attribute::SourceAttribute;
 use cooplan_definitions_lib::source_category::SourceCategory;
 
+use crate::categories::category_files::{find_category_files, is_allowed_file};
 use crate::categories::category_id_generator::set_random_id;
 use crate::categories::category_id_tracker::CategoryEntry;
 use crate::categories::category_id_tracker_file_io::CategoryIdTrackerFileIO;
 use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
-use crate::categories::validations::id_tracking_validation::IdTrackingValidation;
-use crate::categories::validations::selectable_as_last_validation::SelectableAsLastValidation;
-use crate::categories::validations::validation::Validation;
+use crate::categories::category_id_tracker_memory_io::CategoryIdTrackerMemoryIO;
+use crate::categories::category_tree_order::{sort_by_parent, ParentReference};
+use crate::categories::validations::category_cycle_validation::CategoryCycleValidation;
+use crate::categories::validations::directory_layout_validation::expected_category_path;
+use crate::categories::validations::validation::Validation as CategoryValidation;
 use crate::config::Config;
 use crate::config_file_reader::ConfigFileReader;
 use crate::config_reader::ConfigReader;
-use crate::error::{Error, ErrorKind};
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::paths::Paths;
+use crate::severity::Severity;
+use crate::source_location::{SourceLocation, SourceLocator};
+use crate::validation_registry::{ValidationContext, ValidationRegistry};
 
-const CONFIG_FILE_PATH: &str = "./config.json";
-const CATEGORIES_ROOT: &str = "./categories/";
+/// Category read from its file, alongside the means for writing it back.
+struct SourceCategoryFile {
+    source_category: SourceCategory,
+    /// Extension of each attribute, in the same order as the source category's attributes.
+    attribute_extensions: Vec<AttributeExtension>,
+    path: String,
+    io: Box<dyn CategoryIO>,
+}
+
+/// Id that has been generated for a category, for one of its attributes or for one of their options.
+pub struct GeneratedId {
+    pub category: String,
+    pub attribute: Option<String>,
+    pub option: Option<String>,
+    pub id: String,
+}
+
+/// Category file that has been moved to where the category's parents place it.
+pub struct MovedFile {
+    pub category: String,
+    pub from: String,
+    pub to: String,
+}
 
 /// (C)ontinuous (I)ntegration tool that provides means for integrating and validating changes for
 /// product's definitions.
@@ -40,103 +78,247 @@ const CATEGORIES_ROOT: &str = "./categories/";
 ///     * ID generation - new attributes are automatically assigned a new id.
 ///     * ID tracking - attributes cannot be removed, therefore assuring backwards compatibility at all times.
 ///     * Data type validation - assure attributes have valid and recognizable data types specified within a
-/// configuration file.
-///     * Data constant validation - assure attributes do not change their data type.
-/// (todo: allow compatible data type changes)
+///       configuration file.
+///     * Data constant validation - assure attributes do not change their data type, unless the change is
+///       a widening specified within a configuration file.
+///     * Tracked fields validation - assure attributes do not change their name, unit or category, unless
+///       the change is allowed within the configuration file.
+///
+/// Besides the predefined logics (`run_ci_logic`, `run_generate_ids_logic` and `run_check_logic`), each
+/// step can be run on its own:
+///
+/// 1. `load` - reads the categories and builds their tree, generating in memory the missing ids.
+/// 2. `validate` - runs the chosen validations, collecting the problems found within `diagnostics`.
+/// 3. `apply_generated_ids` - writes the generated ids into the tracking files and the categories' files.
 pub struct CI {
+    paths: Paths,
     config: Config,
-    name_id_links: HashMap<String, String>,
+    category_id_tracker_io: Rc<dyn CategoryIdTrackerIO>,
+    attribute_tracker_io: Rc<dyn AttributeTrackerIO>,
+    option_tracker_io: Rc<dyn OptionTrackerIO>,
+    loaded_category_id_tracker_io: Rc<CategoryIdTrackerMemoryIO>,
+    loaded_attribute_tracker_io: Rc<AttributeTrackerMemoryIO>,
+    loaded_option_tracker_io: Rc<OptionTrackerMemoryIO>,
+    pending_category_entries: Vec<CategoryEntry>,
+    pending_attribute_entries: Vec<AttributeEntry>,
+    pending_option_entries: Vec<OptionEntry>,
+    /// Whether the loaded attributes' entries have been migrated or updated, therefore the whole tracker
+    /// has to be written.
+    outdated_attribute_tracker: bool,
+    generated_ids: Vec<GeneratedId>,
+    fix_layout: bool,
+    moved_files: Vec<MovedFile>,
+    name_id_links: HashMap<String, Vec<String>>,
     categories_io: HashMap<String, Box<dyn CategoryIO>>,
+    categories_path: HashMap<String, String>,
     categories_mapping: HashMap<String, Rc<RefCell<Category>>>,
+    /// Extension of each attribute that has any, with the attribute's id as key.
+    attribute_extensions: HashMap<String, AttributeExtension>,
     root_categories: Vec<Rc<RefCell<Category>>>,
+    validation_registry: ValidationRegistry,
+    /// Problems found by `load`, which are part of the diagnostics of every later validation run.
+    load_diagnostics: Diagnostics,
+    diagnostics: Diagnostics,
 }
 
 impl CI {
-    pub fn try_new() -> Result<CI, Error> {
-        Ok(CI {
-            config: {
-                let config_reader = ConfigFileReader::new(CONFIG_FILE_PATH);
+    /// Creates a CI whose configuration and trackers are read from the files within `paths`.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToReadConfig` - if the configuration file could not be read.
+    pub fn try_new(paths: Paths) -> Result<CI, Error> {
+        let config_reader = ConfigFileReader::new(paths.config());
 
-                match config_reader.read() {
-                    Ok(config) => config,
-                    Err(error) => {
-                        return Err(Error::new(
-                            ErrorKind::FailedToReadConfig,
-                            format!("failed to read configuration file: {}", error).as_str(),
-                        ))
-                    }
-                }
-            },
+        let config = match config_reader.read() {
+            Ok(config) => config,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadConfig,
+                    "failed to read configuration file",
+                )
+                .with_source(error))
+            }
+        };
+
+        let category_id_tracker_io =
+            Rc::new(CategoryIdTrackerFileIO::new(paths.category_id_tracker()));
+        let attribute_tracker_io =
+            Rc::new(AttributeTrackerFileIO::new(paths.attribute_id_tracker()));
+
+        let option_tracker_io = Rc::new(OptionTrackerFileIO::new(paths.option_id_tracker()));
+
+        let mut ci = CI::new(paths, config, category_id_tracker_io, attribute_tracker_io);
+        ci.set_option_tracker_io(option_tracker_io);
+
+        Ok(ci)
+    }
+
+    /// Creates a CI with the given configuration and tracking storages.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - only the categories' root directory is used, given the configuration and trackers are provided.
+    /// * `config` - configuration of the validations.
+    /// * `category_id_tracker_io` - storage of the categories' tracked ids.
+    /// * `attribute_tracker_io` - storage of the attributes' tracked ids and data types.
+    ///
+    /// Options are tracked in memory, unless their storage is set through `set_option_tracker_io`.
+    pub fn new(
+        paths: Paths,
+        config: Config,
+        category_id_tracker_io: Rc<dyn CategoryIdTrackerIO>,
+        attribute_tracker_io: Rc<dyn AttributeTrackerIO>,
+    ) -> CI {
+        CI {
+            paths,
+            config,
+            category_id_tracker_io,
+            attribute_tracker_io,
+            option_tracker_io: Rc::new(OptionTrackerMemoryIO::new(HashMap::new())),
+            loaded_category_id_tracker_io: Rc::new(CategoryIdTrackerMemoryIO::new(HashMap::new())),
+            loaded_attribute_tracker_io: Rc::new(AttributeTrackerMemoryIO::new(HashMap::new())),
+            loaded_option_tracker_io: Rc::new(OptionTrackerMemoryIO::new(HashMap::new())),
+            pending_category_entries: Vec::new(),
+            pending_attribute_entries: Vec::new(),
+            pending_option_entries: Vec::new(),
+            outdated_attribute_tracker: false,
+            generated_ids: Vec::new(),
+            fix_layout: false,
+            moved_files: Vec::new(),
             name_id_links: HashMap::new(),
             categories_io: HashMap::new(),
+            categories_path: HashMap::new(),
             categories_mapping: HashMap::new(),
+            attribute_extensions: HashMap::new(),
             root_categories: Vec::new(),
-        })
+            validation_registry: ValidationRegistry::default(),
+            load_diagnostics: Diagnostics::new(),
+            diagnostics: Diagnostics::new(),
+        }
     }
 
-    fn link_name_with_id(&mut self, name: &str, id: &str) -> Result<(), Error> {
-        match self.name_id_links.get(name) {
-            Some(linked_id) => {
-                if !linked_id.as_str().eq(name) {
-                    return Err(Error::new(
-                        ErrorKind::DuplicatedName,
-                        format!(
-                            "category name '{}' is duplicated used for the following ids: {}, {}",
-                            name, linked_id, name
-                        )
-                        .as_str(),
-                    ));
-                }
+    /// Sets the storage of the enumerated attributes' tracked options.
+    pub fn set_option_tracker_io(&mut self, option_tracker_io: Rc<dyn OptionTrackerIO>) {
+        self.option_tracker_io = option_tracker_io;
+    }
+
+    /// Links the category's name with its id. Names shared by several categories are linked with all
+    /// of their ids, and reported by the duplicate category name validation.
+    fn link_name_with_id(&mut self, name: &str, id: &str) {
+        let linked_ids = self.name_id_links.entry(name.to_string()).or_default();
+
+        if !linked_ids.iter().any(|linked_id| linked_id == id) {
+            linked_ids.push(id.to_string());
+        }
+    }
+
+    /// Reads every category definition, except the files the configuration allows besides them.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToReadCategory` - if the categories could not be found, or a category's file could not be read.
+    ///
+    /// If several categories could not be read, their errors are aggregated.
+    ///
+    /// The files that have been read, but do not hold a valid definition, are left out and reported as
+    /// `InvalidCategoryFile` problems within the load's diagnostics, so that the rest of the categories are
+    /// still loaded and validated.
+    fn read_source_categories(&mut self) -> Result<Vec<SourceCategoryFile>, Error> {
+        let category_files = match find_category_files(self.paths.categories_root()) {
+            Ok(category_files) => category_files,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadCategory,
+                    "failed to read categories",
+                )
+                .with_source(error))
             }
-            None => {
-                self.name_id_links
-                    .insert(String::from(name), String::from(id));
+        };
+
+        let allowed_files = self.config.allowed_files();
+        let mut source_categories = Vec::new();
+        let mut errors: Vec<Error> = Vec::new();
+
+        for category_file in category_files {
+            if is_allowed_file(
+                self.paths.categories_root(),
+                category_file.as_str(),
+                allowed_files.as_slice(),
+            ) {
+                continue;
             }
-        }
 
-        Ok(())
-    }
+            let mut category_io: Box<dyn CategoryIO> = Box::new(CategoryFileIO::new(
+                self.paths.categories_root().to_string(),
+                category_file.clone(),
+            ));
 
-    fn read_source_categories(&mut self) -> Result<Vec<SourceCategory>, Error> {
-        match build_for_all_categories(CATEGORIES_ROOT.to_string()) {
-            Ok(categories_io) => {
-                let mut source_categories = Vec::new();
+            let read_result = match category_io.read() {
+                Ok(source_category) => match read_attribute_extensions(category_file.as_str()) {
+                    Ok(attribute_extensions) => Ok((source_category, attribute_extensions)),
+                    Err(error) => Err(error),
+                },
+                Err(error) => Err(error),
+            };
 
-                for mut category_io in categories_io {
-                    match category_io.read() {
-                        Ok(source_category) => {
-                            self.categories_io
-                                .insert(source_category.name.clone(), category_io);
-                            source_categories.push(source_category);
-                        }
-                        Err(error) => {
-                            return Err(Error::new(
-                                ErrorKind::FailedToReadCategory,
-                                format!("failed to read category: {}", error).as_str(),
-                            ));
-                        }
-                    }
+            match read_result {
+                Ok((source_category, attribute_extensions)) => {
+                    source_categories.push(SourceCategoryFile {
+                        source_category,
+                        attribute_extensions,
+                        path: category_file,
+                        io: category_io,
+                    })
                 }
+                Err(error) => {
+                    let message = error.to_string();
+                    let location =
+                        SourceLocation::from_message(category_file.as_str(), message.as_str());
+
+                    // The file has been read, but it does not hold a valid definition.
+                    if error.kind() == std::io::ErrorKind::InvalidData {
+                        let mut diagnostic = Diagnostic::new(
+                            ErrorKind::InvalidCategoryFile,
+                            format!("invalid category definition {}", message).as_str(),
+                        );
+                        diagnostic.set_location(location);
+
+                        self.load_diagnostics.push(diagnostic);
+                        continue;
+                    }
 
-                Ok(source_categories)
+                    errors.push(
+                        Error::new(ErrorKind::FailedToReadCategory, "failed to read category")
+                            .with_source(error)
+                            .with_location(location),
+                    );
+                }
             }
-            Err(error) => Err(Error::new(
+        }
+
+        match errors.len() {
+            0 => Ok(source_categories),
+            1 => Err(errors.remove(0)),
+            error_count => Err(Error::aggregate(
                 ErrorKind::FailedToReadCategory,
-                format!("failed to read categories: {}", error).as_str(),
+                format!("failed to read {} categories", error_count).as_str(),
+                errors,
             )),
         }
     }
 
-    fn generate_ids(&mut self, source_categories: &mut Vec<SourceCategory>) -> Result<(), Error> {
-        for mut source_category in source_categories {
+    fn generate_ids(
+        &mut self,
+        source_category_files: &mut [SourceCategoryFile],
+    ) -> Result<(), Error> {
+        for source_category_file in source_category_files {
+            let source_category = &mut source_category_file.source_category;
+            let attribute_extensions = &mut source_category_file.attribute_extensions;
+
             match &source_category.id {
-                Some(id) => {
-                    match self.link_name_with_id(source_category.name.as_str(), id.as_str()) {
-                        Ok(_) => (),
-                        Err(error) => return Err(error),
-                    }
-                }
-                None => match set_random_id(&mut source_category) {
+                Some(id) => self.link_name_with_id(source_category.name.as_str(), id.as_str()),
+                None => match set_random_id(source_category) {
                     Ok(_) => {
                         if source_category.id.is_none() {
                             return Err(Error::new(
@@ -145,24 +327,35 @@ impl CI {
                             ));
                         }
 
-                        match self.link_name_with_id(
+                        self.link_name_with_id(
                             source_category.name.as_str(),
                             source_category.id.as_ref().unwrap().as_str(),
-                        ) {
-                            Ok(_) => (),
-                            Err(error) => return Err(error),
-                        }
+                        );
 
-                        match self.update_category_id_tracker(&source_category) {
+                        match self.update_category_id_tracker(source_category) {
                             Ok(_) => (),
                             Err(error) => return Err(error),
                         }
+
+                        self.generated_ids.push(GeneratedId {
+                            category: source_category.name.clone(),
+                            attribute: None,
+                            option: None,
+                            id: source_category.id.clone().unwrap(),
+                        });
                     }
                     Err(error) => return Err(error),
                 },
             }
 
-            match self.generate_ids_for_attributes(source_category.attributes.as_mut_slice()) {
+            let category_id = source_category.id.clone().unwrap_or_default();
+
+            match self.generate_ids_for_attributes(
+                category_id.as_str(),
+                source_category.name.as_str(),
+                source_category.attributes.as_mut_slice(),
+                attribute_extensions.as_mut_slice(),
+            ) {
                 Ok(_) => (),
                 Err(error) => return Err(error),
             }
@@ -171,20 +364,25 @@ impl CI {
         Ok(())
     }
 
-    fn update_category_id_tracker(&self, source_category: &SourceCategory) -> Result<(), Error> {
+    fn update_category_id_tracker(
+        &mut self,
+        source_category: &SourceCategory,
+    ) -> Result<(), Error> {
         match source_category.id.clone() {
             Some(id) => {
                 let entry = CategoryEntry { id };
 
-                let category_id_tracker_io: Box<dyn CategoryIdTrackerIO> =
-                    Box::new(CategoryIdTrackerFileIO::new());
+                match self.loaded_category_id_tracker_io.write_entry(&entry) {
+                    Ok(_) => {
+                        self.pending_category_entries.push(entry);
 
-                match category_id_tracker_io.write_entry(&entry) {
-                    Ok(_) => Ok(()),
+                        Ok(())
+                    }
                     Err(error) => Err(Error::new(
                         ErrorKind::FailedToWriteCategory,
-                        format!("failed to write category's entry: {}", error).as_str(),
-                    )),
+                        "failed to write category's entry",
+                    )
+                    .with_source(error)),
                 }
             }
             None => Err(Error::new(
@@ -195,10 +393,18 @@ impl CI {
     }
 
     fn generate_ids_for_attributes(
-        &self,
+        &mut self,
+        category_id: &str,
+        category_name: &str,
         source_attributes: &mut [SourceAttribute],
+        attribute_extensions: &mut [AttributeExtension],
     ) -> Result<(), Error> {
-        for source_attribute in source_attributes {
+        for (index, source_attribute) in source_attributes.iter_mut().enumerate() {
+            let constraints = match attribute_extensions.get(index) {
+                Some(attribute_extension) => attribute_extension.constraints.clone(),
+                None => Constraints::default(),
+            };
+
             match &source_attribute.id {
                 Some(_) => (),
                 None => {
@@ -208,35 +414,121 @@ impl CI {
                         Err(error) => return Err(error),
                     }
 
-                    match self.update_attribute_tracker(source_attribute) {
+                    match self.update_attribute_tracker(category_id, source_attribute, &constraints)
+                    {
                         Ok(_) => (),
                         Err(error) => return Err(error),
                     }
+
+                    self.generated_ids.push(GeneratedId {
+                        category: category_name.to_string(),
+                        attribute: Some(source_attribute.name.clone()),
+                        option: None,
+                        id: source_attribute.id.clone().unwrap(),
+                    });
+                }
+            }
+
+            if let Some(attribute_extension) = attribute_extensions.get_mut(index) {
+                match self.generate_ids_for_options(
+                    category_name,
+                    source_attribute,
+                    attribute_extension,
+                ) {
+                    Ok(_) => (),
+                    Err(error) => return Err(error),
+                }
+
+                if let (Some(id), false) = (&source_attribute.id, attribute_extension.is_empty()) {
+                    self.attribute_extensions
+                        .insert(id.clone(), attribute_extension.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn generate_ids_for_options(
+        &mut self,
+        category_name: &str,
+        source_attribute: &SourceAttribute,
+        attribute_extension: &mut AttributeExtension,
+    ) -> Result<(), Error> {
+        let attribute_id = match &source_attribute.id {
+            Some(attribute_id) => attribute_id.clone(),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::MissingId,
+                    format!("attribute '{}' has no id", source_attribute.name).as_str(),
+                ))
+            }
+        };
+
+        for option in attribute_extension.options.iter_mut() {
+            if option.id.is_some() {
+                continue;
+            }
+
+            match crate::attributes::option_id_generator::set_random_id(option) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+
+            let entry = OptionEntry {
+                id: option.id.clone().unwrap(),
+                attribute_id: attribute_id.clone(),
+                name: option.name.clone(),
+            };
+
+            match self.loaded_option_tracker_io.write_entry(&entry) {
+                Ok(_) => self.pending_option_entries.push(entry),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToWriteAttribute,
+                        "failed to write option's entry",
+                    )
+                    .with_source(error))
                 }
             }
+
+            self.generated_ids.push(GeneratedId {
+                category: category_name.to_string(),
+                attribute: Some(source_attribute.name.clone()),
+                option: Some(option.name.clone()),
+                id: option.id.clone().unwrap(),
+            });
         }
 
         Ok(())
     }
 
-    fn update_attribute_tracker(&self, source_attribute: &SourceAttribute) -> Result<(), Error> {
+    fn update_attribute_tracker(
+        &mut self,
+        category_id: &str,
+        source_attribute: &SourceAttribute,
+        constraints: &Constraints,
+    ) -> Result<(), Error> {
         match source_attribute.id.clone() {
             Some(id) => {
                 let entry = AttributeEntry {
                     id,
                     data_type: source_attribute.data_type.clone(),
+                    unit: Some(source_attribute.unit.clone().unwrap_or_default()),
+                    name: Some(source_attribute.name.clone()),
+                    category_id: Some(category_id.to_string()),
+                    constraints: Some(constraints.clone()),
+                    optional: Some(source_attribute.optional.unwrap_or(false)),
                 };
 
-                let attribute_tracker_io: Box<dyn AttributeTrackerIO> =
-                    Box::new(AttributeTrackerFileIO::new());
-
-                match attribute_tracker_io.write_entry(&entry) {
-                    Ok(_) => (),
+                match self.loaded_attribute_tracker_io.write_entry(&entry) {
+                    Ok(_) => self.pending_attribute_entries.push(entry),
                     Err(error) => {
                         return Err(Error::new(
                             ErrorKind::FailedToWriteAttribute,
-                            format!("failed to write attribute: {}", error).as_str(),
-                        ))
+                            "failed to write attribute",
+                        )
+                        .with_source(error))
                     }
                 }
             }
@@ -250,229 +542,482 @@ impl CI {
         Ok(())
     }
 
+    /// Maps the source categories into their tree, placing every parent before its children so the
+    /// order they have been read in does not matter.
+    ///
+    /// Every problem preventing the tree from being built is kept within the load's diagnostics as well.
+    ///
+    /// Error kinds:
+    ///
+    /// * `ParentNotFound` - if a category's parent cannot be resolved or is not among the categories.
+    /// * `DuplicatedName` - if a category's parent is referred to by a name used by several categories.
+    /// * `CategoryCycle` - if categories are, through their parents, their own ancestors.
+    /// * `InvalidCategoryTree` - if several of the above have been found, aggregating them.
     fn map_source_categories(
         &mut self,
-        source_categories: Vec<SourceCategory>,
+        source_category_files: Vec<SourceCategoryFile>,
     ) -> Result<(), Error> {
-        for source_category in source_categories {
+        let tracked_categories = match self.loaded_category_id_tracker_io.read_entries() {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadCategory,
+                    "failed to read categories' entries",
+                )
+                .with_source(error))
+            }
+        };
+
+        let mut errors: Vec<Error> = Vec::new();
+        let mut source_categories: Vec<Option<SourceCategory>> = Vec::new();
+        let mut parents: Vec<ParentReference> = Vec::new();
+
+        for source_category_file in source_category_files {
+            let source_category = source_category_file.source_category;
             let id = source_category.id.clone().unwrap();
 
-            match self.create_category_from_source(source_category) {
+            self.categories_path
+                .insert(id.clone(), source_category_file.path);
+            self.categories_io
+                .insert(id.clone(), source_category_file.io);
+
+            match self.resolve_parent_id(&source_category, &tracked_categories) {
+                Ok(parent_id) => {
+                    parents.push(ParentReference {
+                        id,
+                        name: source_category.name.clone(),
+                        parent_id,
+                    });
+                    source_categories.push(Some(source_category));
+                }
+                Err(error) => {
+                    errors.push(self.locate_source_category_error(error, &source_category))
+                }
+            }
+        }
+
+        let order = sort_by_parent(parents.as_slice());
+
+        for index in order.missing_parents {
+            let source_category = source_categories[index].as_ref().unwrap();
+            let parent_id = parents[index].parent_id.clone().unwrap_or_default();
+
+            let error = Error::new(
+                ErrorKind::ParentNotFound,
+                format!(
+                    "{} refers to parent '{}', which is not among the categories",
+                    self.describe_source_category(source_category),
+                    parent_id
+                )
+                .as_str(),
+            )
+            .with_details(ErrorDetails::Id { id: parent_id });
+
+            errors.push(self.locate_source_category_error(error, source_category));
+        }
+
+        // Cycles are reported by their own validation, naming every category of each cycle. Given their
+        // categories cannot be placed within the tree, cycles always fail the load.
+        let mut cycle_diagnostics = Diagnostics::new();
+        CategoryCycleValidation::new(self.categories_path.clone()).validate(
+            parents.as_slice(),
+            order.cycles.as_slice(),
+            &mut cycle_diagnostics,
+        );
+        cycle_diagnostics.resolve_locations(&self.categories_path, &mut SourceLocator::new());
+
+        for error in errors.iter() {
+            self.load_diagnostics.push(Diagnostic::from(error));
+        }
+
+        for diagnostic in cycle_diagnostics.as_slice() {
+            errors.push(Error::from(diagnostic));
+        }
+
+        self.load_diagnostics.append(cycle_diagnostics);
+
+        if errors.len() == 1 {
+            return Err(errors.remove(0));
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::aggregate(
+                ErrorKind::InvalidCategoryTree,
+                format!(
+                    "category tree cannot be built, {} problem(s) found",
+                    errors.len()
+                )
+                .as_str(),
+                errors,
+            ));
+        }
+
+        for index in order.sorted {
+            let source_category = source_categories[index].take().unwrap();
+            let id = parents[index].id.clone();
+            let parent_id = parents[index].parent_id.clone();
+            let name = source_category.name.clone();
+
+            match self.create_category_from_source(source_category, parent_id) {
                 Ok(category) => {
                     self.categories_mapping.insert(id, category);
                 }
-                Err(error) => return Err(error),
+                Err(error) => {
+                    if error.location().is_some() {
+                        return Err(error);
+                    }
+
+                    return Err(error.with_location(self.locate_category(&id, &name)));
+                }
             }
         }
 
         Ok(())
     }
 
-    fn create_category_from_source(
-        &mut self,
-        source_category: SourceCategory,
-    ) -> Result<Rc<RefCell<Category>>, Error> {
-        match &source_category.id {
-            Some(id) => match &source_category.parent_name {
-                Some(_) => self.create_category_from_source_with_parent(source_category),
-                None => {
-                    let attributes =
-                        match SourceAttribute::to_attributes(source_category.attributes.as_slice())
-                        {
-                            Ok(attributes) => attributes,
-                            Err(error) => return Err(Error::from(error)),
-                        };
-
-                    let category = Category::new(
-                        id.clone(),
-                        source_category.name,
-                        source_category.selectable_as_last.unwrap_or(false),
-                        attributes,
-                    );
+    fn locate_category(&self, id: &str, name: &str) -> SourceLocation {
+        SourceLocator::new().locate_category(
+            self.categories_path
+                .get(id)
+                .map_or("", |path| path.as_str()),
+            Some(id),
+            Some(name),
+        )
+    }
+
+    /// Attaches the location of the category's file to the error, unless it already has one.
+    fn locate_source_category_error(
+        &self,
+        error: Error,
+        source_category: &SourceCategory,
+    ) -> Error {
+        if error.location().is_some() {
+            return error;
+        }
 
-                    self.categories_mapping
-                        .insert(id.clone(), Rc::clone(&category));
-                    self.root_categories.push(Rc::clone(&category));
+        let id = source_category.id.clone().unwrap_or_default();
 
-                    Ok(category)
-                }
-            },
-            None => Err(Error::new(
-                ErrorKind::MissingId,
-                "cannot create root category from source with no id",
-            )),
+        error.with_location(self.locate_category(&id, &source_category.name))
+    }
+
+    /// Description of the category that includes the file it is defined in, e.g. for naming orphans.
+    fn describe_source_category(&self, source_category: &SourceCategory) -> String {
+        match source_category
+            .id
+            .as_ref()
+            .and_then(|id| self.categories_path.get(id))
+        {
+            Some(path) => format!("category '{}' defined in '{}'", source_category.name, path),
+            None => format!("category '{}'", source_category.name),
         }
     }
 
-    fn create_category_from_source_with_parent(
-        &mut self,
-        source_category: SourceCategory,
-    ) -> Result<Rc<RefCell<Category>>, Error> {
-        if source_category.id.is_none() {
-            return Err(Error::new(
-                ErrorKind::MissingId,
-                format!("unexpected source category with no id").as_str(),
-            ));
+    /// Id of the category's parent, resolved by the `parent` id and, for legacy files without it, by
+    /// the parent's name. `None` if the category is a root category.
+    ///
+    /// Error kinds:
+    ///
+    /// * `ParentNotFound` - if the parent's id is not tracked, or no category has the parent's name.
+    /// * `DuplicatedName` - if the parent's name is used by several categories.
+    fn resolve_parent_id(
+        &self,
+        source_category: &SourceCategory,
+        tracked_categories: &HashMap<String, CategoryEntry>,
+    ) -> Result<Option<String>, Error> {
+        if let Some(parent_id) = &source_category.parent {
+            if !tracked_categories.contains_key(parent_id) {
+                return Err(Error::new(
+                    ErrorKind::ParentNotFound,
+                    format!(
+                        "{} refers to parent '{}', which is not tracked within the category id tracker",
+                        self.describe_source_category(source_category),
+                        parent_id
+                    )
+                    .as_str(),
+                )
+                .with_details(ErrorDetails::Id {
+                    id: parent_id.clone(),
+                }));
+            }
+
+            return Ok(Some(parent_id.clone()));
         }
 
-        if source_category.parent_name.is_none() {
-            return Err(Error::new(
+        let parent_name = match &source_category.parent_name {
+            Some(parent_name) => parent_name,
+            None => return Ok(None),
+        };
+
+        match self.name_id_links.get(parent_name) {
+            Some(ids) if ids.len() == 1 => Ok(Some(ids[0].clone())),
+            Some(ids) => Err(Error::new(
+                ErrorKind::DuplicatedName,
+                format!(
+                    "parent of {} is ambiguous, given the name '{}' is used by the following ids: {}",
+                    self.describe_source_category(source_category),
+                    parent_name,
+                    ids.join(", ")
+                )
+                .as_str(),
+            )
+            .with_details(ErrorDetails::Name {
+                name: parent_name.clone(),
+                ids: ids.clone(),
+            })),
+            None => Err(Error::new(
                 ErrorKind::ParentNotFound,
-                format!("unexpected parentless source category").as_str(),
-            ));
+                format!(
+                    "{} refers to parent '{}', which does not match any category's name",
+                    self.describe_source_category(source_category),
+                    parent_name
+                )
+                .as_str(),
+            )),
         }
+    }
 
-        let parent_name = source_category.parent_name.unwrap();
-        let parent_id = match self.name_id_links.get(&parent_name) {
-            Some(id) => id,
+    fn create_category_from_source(
+        &mut self,
+        source_category: SourceCategory,
+        parent_id: Option<String>,
+    ) -> Result<Rc<RefCell<Category>>, Error> {
+        let id = match &source_category.id {
+            Some(id) => id.clone(),
             None => {
                 return Err(Error::new(
-                    ErrorKind::IdNotFound,
-                    format!("category name '{}' is not linked with an id", parent_name).as_str(),
+                    ErrorKind::MissingId,
+                    "cannot create category from source with no id",
                 ))
             }
         };
 
-        match self.categories_mapping.get(parent_id) {
-            Some(parent_category) => {
-                let attributes =
-                    match SourceAttribute::to_attributes(source_category.attributes.as_slice()) {
-                        Ok(attributes) => attributes,
-                        Err(error) => return Err(Error::from(error)),
-                    };
+        let attributes = match SourceAttribute::to_attributes(source_category.attributes.as_slice())
+        {
+            Ok(attributes) => attributes,
+            Err(error) => return Err(Error::from(error)),
+        };
 
-                match Category::new_into_parent(
-                    source_category.id.unwrap(),
-                    Rc::downgrade(parent_category),
+        let parent_id = match parent_id {
+            Some(parent_id) => parent_id,
+            None => {
+                let category = Category::new(
+                    id.clone(),
                     source_category.name,
                     source_category.selectable_as_last.unwrap_or(false),
                     attributes,
-                ) {
-                    Ok(category) => Ok(category),
-                    Err(error) => Err(Error::from(error)),
-                }
+                );
+
+                self.categories_mapping.insert(id, Rc::clone(&category));
+                self.root_categories.push(Rc::clone(&category));
+
+                return Ok(category);
             }
+        };
+
+        match self.categories_mapping.get(&parent_id) {
+            Some(parent_category) => match Category::new_into_parent(
+                id,
+                Rc::downgrade(parent_category),
+                source_category.name,
+                source_category.selectable_as_last.unwrap_or(false),
+                attributes,
+            ) {
+                Ok(category) => Ok(category),
+                Err(error) => Err(Error::from(error)),
+            },
             None => Err(Error::new(
                 ErrorKind::ParentNotAvailable,
-                format!("parent '{}' has not been read yet", parent_id).as_str(),
+                format!(
+                    "parent '{}' of {} has not been placed within the tree before it",
+                    parent_id,
+                    self.describe_source_category(&source_category)
+                )
+                .as_str(),
             )),
         }
     }
 
-    fn run_validations(&mut self) -> Result<(), Error> {
-        let id_tracking_validation = IdTrackingValidation::new();
+    /// Category validations of the registry that the configuration has not turned off.
+    ///
+    /// To be called after `load`, given the id tracking validation relies on the loaded ids.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToReadAttribute` - if the attributes' entries could not be read.
+    /// * `UnknownValidation` - if the configuration refers to a validation that is not registered.
+    pub fn enabled_category_validations(&self) -> Result<Vec<Box<dyn CategoryValidation>>, Error> {
+        self.with_validation_context(|context| {
+            self.validation_registry
+                .create_category_validations(context)
+        })
+    }
 
-        match id_tracking_validation.validate(self.root_categories.as_slice()) {
-            Ok(_) => (),
-            Err(error) => return Err(error),
-        }
+    /// Attribute validations of the registry that the configuration has not turned off.
+    ///
+    /// To be called after `load`, given the tracking validations rely on the loaded entries.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToReadAttribute` - if the attributes' entries could not be read.
+    /// * `UnknownValidation` - if the configuration refers to a validation that is not registered.
+    pub fn enabled_attribute_validations(
+        &self,
+    ) -> Result<Vec<Rc<RefCell<dyn AttributeValidation>>>, Error> {
+        self.with_validation_context(|context| {
+            self.validation_registry
+                .create_attribute_validations(context)
+        })
+    }
 
-        let selectable = SelectableAsLastValidation::new();
+    /// Registry the enabled validations are created from, where custom validations can be registered.
+    pub fn validation_registry_mut(&mut self) -> &mut ValidationRegistry {
+        &mut self.validation_registry
+    }
 
-        match selectable.validate(self.root_categories.as_slice()) {
-            Ok(_) => (),
-            Err(error) => return Err(error),
-        }
+    fn with_validation_context<T>(
+        &self,
+        create: impl FnOnce(&ValidationContext) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let option_entries = match self.loaded_option_tracker_io.read_entries() {
+            Ok(option_entries) => option_entries,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadAttribute,
+                    "failed to read options' entries",
+                )
+                .with_source(error))
+            }
+        };
 
-        match self.run_attributes_validations() {
-            Ok(_) => (),
-            Err(error) => return Err(error),
+        match self.loaded_attribute_tracker_io.read_entries() {
+            Ok(attribute_entries) => create(&ValidationContext {
+                config: &self.config,
+                category_id_tracker_io: Rc::clone(&self.loaded_category_id_tracker_io)
+                    as Rc<dyn CategoryIdTrackerIO>,
+                attribute_entries: &attribute_entries,
+                attribute_extensions: &self.attribute_extensions,
+                option_entries: &option_entries,
+                categories_path: &self.categories_path,
+                categories_root: self.paths.categories_root(),
+            }),
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToReadAttribute,
+                "failed to read attributes' entries",
+            )
+            .with_source(error)),
         }
-
-        Ok(())
     }
 
-    fn run_attributes_validations(&mut self) -> Result<(), Error> {
-        match self.initialize_attributes_validations() {
-            Ok(attribute_validations) => {
-                for category_pointer in self.root_categories.as_slice() {
-                    match category_pointer.try_borrow() {
-                        Ok(category) => {
-                            match self.run_attributes_validations_for_category(
-                                category,
-                                attribute_validations.as_slice(),
-                            ) {
-                                Ok(_) => (),
-                                Err(error) => return Err(error),
-                            }
-                        }
-                        Err(error) => {
-                            return Err(Error::new(
-                                ErrorKind::FailedToBorrowCategory,
-                                format!("failed to borrow category: {}", error).as_str(),
-                            ))
-                        }
-                    }
-                }
-
-                for attribute_validation_pointer in attribute_validations {
-                    match attribute_validation_pointer.try_borrow_mut() {
-                        Ok(mut attribute_validation) => match attribute_validation.complete() {
-                            Ok(_) => (),
-                            Err(error) => return Err(error),
-                        },
-                        Err(error) => {
-                            return Err(Error::new(
-                                ErrorKind::FailedToBorrowAttributeValidation,
-                                format!("failed to borrow attribute validation: {}", error)
-                                    .as_str(),
-                            ))
-                        }
-                    }
-                }
+    /// Runs the given validations over the loaded categories, collecting all the problems found
+    /// within `diagnostics`, alongside the ones found by `load`.
+    ///
+    /// Error kinds:
+    ///
+    /// * `ValidationFailed` - if any problem with `Error` severity has been found.
+    pub fn validate(
+        &mut self,
+        category_validations: &[Box<dyn CategoryValidation>],
+        attribute_validations: &[Rc<RefCell<dyn AttributeValidation>>],
+    ) -> Result<(), Error> {
+        let mut diagnostics = self.load_diagnostics.clone();
 
-                Ok(())
+        for category_validation in category_validations {
+            match category_validation.validate(self.root_categories.as_slice(), &mut diagnostics) {
+                Ok(_) => (),
+                Err(error) => return Err(error),
             }
-            Err(error) => Err(error),
         }
+
+        match self.run_attributes_validations(attribute_validations, &mut diagnostics) {
+            Ok(_) => (),
+            Err(error) => return Err(error),
+        }
+
+        diagnostics.resolve_locations(&self.categories_path, &mut SourceLocator::new());
+        self.diagnostics = diagnostics;
+
+        let error_count = self.diagnostics.count(Severity::Error);
+
+        if error_count == 0 {
+            return Ok(());
+        }
+
+        let errors: Vec<Error> = self
+            .diagnostics
+            .as_slice()
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() == Severity::Error)
+            .map(Error::from)
+            .collect();
+
+        Err(Error::aggregate(
+            ErrorKind::ValidationFailed,
+            format!("validation failed with {} error(s)", error_count).as_str(),
+            errors,
+        ))
+    }
+
+    /// Problems found by the last validation run.
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
     }
 
-    fn initialize_attributes_validations(
+    fn run_attributes_validations(
         &self,
-    ) -> Result<Vec<Rc<RefCell<dyn crate::attributes::validations::validation::Validation>>>, Error>
-    {
-        let mut validations: Vec<
-            Rc<RefCell<dyn crate::attributes::validations::validation::Validation>>,
-        > = Vec::new();
-
-        let attribute_entries = AttributeTrackerFileIO::new();
-
-        match attribute_entries.read_entries() {
-            Ok(entries) => {
-                validations.push(Rc::new(RefCell::new(crate::attributes::validations::id_tracking_validation::IdTrackingValidation::new(&entries))));
-                validations.push(Rc::new(RefCell::new(DataTypeConstantValidation::new(
-                    &entries,
-                ))));
-                validations.push(Rc::new(RefCell::new(
-                    ReservedAttributeNamesValidation::new(self.config.reserved_keywords()),
-                )));
-            }
-            Err(error) => {
-                return Err(Error::new(
-                    ErrorKind::FailedToReadAttribute,
-                    format!("failed to read attributes' entries: {}", error).as_str(),
-                ))
+        attribute_validations: &[Rc<RefCell<dyn AttributeValidation>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        for category_pointer in self.root_categories.as_slice() {
+            match category_pointer.try_borrow() {
+                Ok(category) => {
+                    match self.run_attributes_validations_for_category(
+                        category,
+                        attribute_validations,
+                        diagnostics,
+                    ) {
+                        Ok(_) => (),
+                        Err(error) => return Err(error),
+                    }
+                }
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowCategory,
+                        "failed to borrow category",
+                    )
+                    .with_source(error))
+                }
             }
         }
 
-        validations.push(Rc::new(RefCell::new(DataTypeValidation::new(
-            self.config.valid_data_types(),
-        ))));
+        for attribute_validation_pointer in attribute_validations {
+            match attribute_validation_pointer.try_borrow_mut() {
+                Ok(mut attribute_validation) => match attribute_validation.complete(diagnostics) {
+                    Ok(_) => (),
+                    Err(error) => return Err(error),
+                },
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowAttributeValidation,
+                        "failed to borrow attribute validation",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
 
-        Ok(validations)
+        Ok(())
     }
 
     fn run_attributes_validations_for_category(
         &self,
         category: Ref<Category>,
-        attribute_validations: &[Rc<
-            RefCell<dyn crate::attributes::validations::validation::Validation>,
-        >],
+        attribute_validations: &[Rc<RefCell<dyn AttributeValidation>>],
+        diagnostics: &mut Diagnostics,
     ) -> Result<(), Error> {
         for attribute_validation_pointer in attribute_validations {
             match attribute_validation_pointer.try_borrow_mut() {
                 Ok(mut attribute_validation) => {
-                    match attribute_validation.partially_validate(category.attributes.as_slice()) {
+                    match attribute_validation.partially_validate(&category, diagnostics) {
                         Ok(_) => (),
                         Err(error) => return Err(error),
                     }
@@ -480,8 +1025,9 @@ impl CI {
                 Err(error) => {
                     return Err(Error::new(
                         ErrorKind::FailedToBorrowAttributeValidation,
-                        format!("failed to borrow attribute validation: {}", error).as_str(),
-                    ))
+                        "failed to borrow attribute validation",
+                    )
+                    .with_source(error))
                 }
             }
         }
@@ -492,6 +1038,7 @@ impl CI {
                     match self.run_attributes_validations_for_category(
                         child_category,
                         attribute_validations,
+                        diagnostics,
                     ) {
                         Ok(_) => (),
                         Err(error) => return Err(error),
@@ -500,8 +1047,9 @@ impl CI {
                 Err(error) => {
                     return Err(Error::new(
                         ErrorKind::FailedToBorrowCategory,
-                        format!("failed to borrow category: {}", error).as_str(),
-                    ));
+                        "failed to borrow category",
+                    )
+                    .with_source(error));
                 }
             }
         }
@@ -509,6 +1057,83 @@ impl CI {
         Ok(())
     }
 
+    /// Writes the ids generated by `load` into the tracking storages and the categories' files.
+    ///
+    /// If the attributes' entries have been migrated or updated by `load`, the attributes' tracking storage is
+    /// rewritten as a whole.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToWriteCategory` - if a category's entry or file could not be written.
+    /// * `FailedToWriteAttribute` - if an attribute's entry could not be written.
+    pub fn apply_generated_ids(&mut self) -> Result<(), Error> {
+        for entry in self.pending_category_entries.drain(..) {
+            match self.category_id_tracker_io.write_entry(&entry) {
+                Ok(_) => (),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToWriteCategory,
+                        "failed to write category's entry",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        if self.outdated_attribute_tracker {
+            // The loaded entries already include the pending ones.
+            self.pending_attribute_entries.clear();
+
+            match self.loaded_attribute_tracker_io.read_entries() {
+                Ok(entries) => match self.attribute_tracker_io.write_entries(&entries) {
+                    Ok(_) => self.outdated_attribute_tracker = false,
+                    Err(error) => {
+                        return Err(Error::new(
+                            ErrorKind::FailedToWriteAttribute,
+                            "failed to write attributes' entries",
+                        )
+                        .with_source(error))
+                    }
+                },
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToReadAttribute,
+                        "failed to read attributes' entries",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        for entry in self.pending_attribute_entries.drain(..) {
+            match self.attribute_tracker_io.write_entry(&entry) {
+                Ok(_) => (),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToWriteAttribute,
+                        "failed to write attribute",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        for entry in self.pending_option_entries.drain(..) {
+            match self.option_tracker_io.write_entry(&entry) {
+                Ok(_) => (),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToWriteAttribute,
+                        "failed to write option's entry",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        self.apply_changes()
+    }
+
     fn apply_changes(&mut self) -> Result<(), Error> {
         for root_category in self.root_categories.as_slice() {
             match self.apply_changes_for_category(root_category) {
@@ -523,18 +1148,27 @@ impl CI {
     fn apply_changes_for_category(&self, category: &Rc<RefCell<Category>>) -> Result<(), Error> {
         match category.try_borrow() {
             Ok(borrowed_category) => {
-                match self.categories_io.get(&borrowed_category.name.clone()) {
+                match self.categories_io.get(&borrowed_category.id) {
                     Some(category_io) => match category_io.write(category) {
-                        Ok(_) => (),
+                        Ok(_) => match self.write_attribute_extensions(&borrowed_category) {
+                            Ok(_) => (),
+                            Err(error) => return Err(error),
+                        },
                         Err(error) => {
-                            return Err(Error::new(
+                            let mut write_error = Error::new(
                                 ErrorKind::FailedToWriteCategory,
                                 format!(
                                     "failed to write category '{}' with id '{}': {}",
                                     borrowed_category.name, borrowed_category.id, error
                                 )
                                 .as_str(),
-                            ))
+                            );
+
+                            if let Some(path) = self.categories_path.get(&borrowed_category.id) {
+                                write_error = write_error.with_location(SourceLocation::new(path));
+                            }
+
+                            return Err(write_error);
                         }
                     },
                     None => {
@@ -560,9 +1194,186 @@ impl CI {
             }
             Err(error) => Err(Error::new(
                 ErrorKind::FailedToBorrowCategory,
-                format!("failed to borrow category: {}", error).as_str(),
-            )),
+                "failed to borrow category",
+            )
+            .with_source(error)),
+        }
+    }
+
+    /// Adds the extensions of the category's attributes into its file, which the category's io writes without them.
+    fn write_attribute_extensions(&self, category: &Category) -> Result<(), Error> {
+        let has_extensions = category
+            .attributes
+            .iter()
+            .any(|attribute| self.attribute_extensions.contains_key(&attribute.id));
+
+        match self.categories_path.get(&category.id) {
+            Some(path) if has_extensions => {
+                match write_attribute_extensions(path.as_str(), &self.attribute_extensions) {
+                    Ok(_) => Ok(()),
+                    Err(error) => Err(Error::new(
+                        ErrorKind::FailedToWriteCategory,
+                        format!(
+                            "failed to write the attributes of category '{}' with id '{}': {}",
+                            category.name, category.id, error
+                        )
+                        .as_str(),
+                    )
+                    .with_location(SourceLocation::new(path))),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads the categories from the .json files within the categories' root directory and builds
+    /// their tree, generating in memory a new id for each category and attribute that has none.
+    ///
+    /// The attributes' entries are then brought up to date in memory: fields that predate their tracking are
+    /// filled with the attributes' current state, and so are the fields whose change has been allowed within
+    /// the configuration's `allowed_attribute_changes`, the data types widened as allowed by its
+    /// `data_type_widenings` and the constraints that have only been loosened.
+    ///
+    /// Nothing is written until `apply_generated_ids` is called. The generated ids can be retrieved
+    /// through `generated_ids`.
+    pub fn load(&mut self) -> Result<(), Error> {
+        self.outdated_attribute_tracker = false;
+        self.generated_ids.clear();
+        self.moved_files.clear();
+        self.pending_category_entries.clear();
+        self.pending_attribute_entries.clear();
+        self.pending_option_entries.clear();
+        self.attribute_extensions.clear();
+        self.name_id_links.clear();
+        self.categories_io.clear();
+        self.categories_path.clear();
+        self.categories_mapping.clear();
+        self.root_categories.clear();
+        self.load_diagnostics = Diagnostics::new();
+        self.diagnostics = Diagnostics::new();
+
+        match self.load_tracked_entries() {
+            Ok(_) => (),
+            Err(error) => return Err(error),
+        }
+
+        let result = match self.read_source_categories() {
+            Ok(mut source_category_files) => match self.generate_ids(&mut source_category_files) {
+                Ok(_) => match self.map_source_categories(source_category_files) {
+                    Ok(_) => self.update_attribute_entries(),
+                    Err(error) => Err(error),
+                },
+                Err(error) => Err(error),
+            },
+            Err(error) => Err(error),
+        };
+
+        self.diagnostics = self.load_diagnostics.clone();
+
+        result
+    }
+
+    /// Migrates the loaded attributes' entries and tracks the allowed changes of their fields.
+    fn update_attribute_entries(&mut self) -> Result<(), Error> {
+        let mut entries = match self.loaded_attribute_tracker_io.read_entries() {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadAttribute,
+                    "failed to read attributes' entries",
+                )
+                .with_source(error))
+            }
+        };
+
+        let data_type_widenings = self.config.data_type_widenings();
+        let mut updated = false;
+
+        for category_pointer in self.categories_mapping.values() {
+            match category_pointer.try_borrow() {
+                Ok(category) => {
+                    for attribute in category.attributes.as_slice() {
+                        if let Some(entry) = entries.get_mut(&attribute.id) {
+                            let constraints = match self.attribute_extensions.get(&attribute.id) {
+                                Some(attribute_extension) => {
+                                    attribute_extension.constraints.clone()
+                                }
+                                None => Constraints::default(),
+                            };
+
+                            if entry.migrate(category.id.as_str(), attribute, &constraints) {
+                                updated = true;
+                            }
+
+                            if entry.accept_loosened_constraints(&constraints, attribute.optional) {
+                                updated = true;
+                            }
+
+                            if data_type_widenings
+                                .allows(entry.data_type.as_str(), attribute.data_type.as_str())
+                                && entry.accept_change(
+                                    TrackedField::DataType,
+                                    category.id.as_str(),
+                                    attribute,
+                                    &constraints,
+                                )
+                            {
+                                updated = true;
+                            }
+
+                            for change in self.config.allowed_attribute_changes(&attribute.id) {
+                                if change.matches(category.id.as_str(), attribute, &constraints)
+                                    && entry.accept_change(
+                                        change.field,
+                                        category.id.as_str(),
+                                        attribute,
+                                        &constraints,
+                                    )
+                                {
+                                    updated = true;
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowCategory,
+                        "failed to borrow category",
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        if !updated {
+            return Ok(());
         }
+
+        match self.loaded_attribute_tracker_io.write_entries(&entries) {
+            Ok(_) => {
+                self.outdated_attribute_tracker = true;
+
+                Ok(())
+            }
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToWriteAttribute,
+                "failed to write attributes' entries",
+            )
+            .with_source(error)),
+        }
+    }
+
+    /// Root categories of the tree built by `load`.
+    pub fn root_categories(&self) -> &[Rc<RefCell<Category>>] {
+        self.root_categories.as_slice()
+    }
+
+    /// Path of the file the category, identified by its id, has been read from.
+    pub fn category_path(&self, category_id: &str) -> Option<&str> {
+        self.categories_path
+            .get(category_id)
+            .map(|path| path.as_str())
     }
 
     /// CI logic:
@@ -572,21 +1383,436 @@ impl CI {
     /// 3. Write the new ids into the tracking files and within the corresponding .json files.
     /// 4. Validate the current state of all categories.
     pub fn run_ci_logic(&mut self) -> Result<(), Error> {
-        match self.read_source_categories() {
-            Ok(mut source_categories) => match self.generate_ids(&mut source_categories) {
-                Ok(_) => match self.map_source_categories(source_categories) {
-                    Ok(_) => match self.apply_changes() {
-                        Ok(_) => match self.run_validations() {
-                            Ok(_) => Ok(()),
-                            Err(error) => Err(error),
-                        },
-                        Err(error) => Err(error),
-                    },
-                    Err(error) => Err(error),
-                },
+        match self.load_and_fix_layout() {
+            Ok(_) => match self.apply_generated_ids() {
+                Ok(_) => self.run_default_validations(),
+                Err(error) => Err(error),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Generate ids logic:
+    ///
+    /// 1. Read categories from .json files within the 'categories' directory.
+    /// 2. Generate new ids for each category that has not an id.
+    /// 3. Write the new ids into the tracking files and within the corresponding .json files.
+    pub fn run_generate_ids_logic(&mut self) -> Result<(), Error> {
+        match self.load_and_fix_layout() {
+            Ok(_) => self.apply_generated_ids(),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Check logic, which does not write any file:
+    ///
+    /// 1. Read categories from .json files within the 'categories' directory.
+    /// 2. Generate, in memory, new ids for each category and attribute that has not an id.
+    /// 3. Validate the current state of all categories.
+    /// 4. Fail if any file would change, be it a category's file or a tracker, e.g. because an id has been
+    ///    generated, a category's file is not written as it would be or the attributes' entries have been migrated.
+    ///    Files that would change are looked for even if the validations fail, in which case both errors are
+    ///    aggregated.
+    ///
+    /// The generated ids can be retrieved through `generated_ids`.
+    pub fn run_check_logic(&mut self) -> Result<(), Error> {
+        match self.load() {
+            Ok(_) => {
+                // Pending changes are looked for even if the validations fail, so both are reported at once.
+                let validation_result = self.run_default_validations();
+                let pending_changes_result = self.ensure_no_pending_changes();
+
+                match (validation_result, pending_changes_result) {
+                    (Ok(_), result) => result,
+                    (Err(error), Ok(_)) => Err(error),
+                    (Err(validation_error), Err(pending_changes_error)) => Err(Error::aggregate(
+                        validation_error.kind(),
+                        format!(
+                            "{} and {}",
+                            validation_error.message, pending_changes_error.message
+                        )
+                        .as_str(),
+                        vec![validation_error, pending_changes_error],
+                    )),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Sets whether `run_ci_logic` and `run_generate_ids_logic` move the files of the categories that are not
+    /// where their parents place them, before writing any change.
+    pub fn set_fix_layout(&mut self, fix_layout: bool) {
+        self.fix_layout = fix_layout;
+    }
+
+    fn load_and_fix_layout(&mut self) -> Result<(), Error> {
+        match self.load() {
+            Ok(_) => {
+                if self.fix_layout {
+                    self.fix_directory_layout()
+                } else {
+                    Ok(())
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Moves the file of each category that is not where its parents place it, i.e. within the
+    /// directories named after its ancestors and named after the category. Existing files are never
+    /// overwritten, and the directories left empty are removed.
+    ///
+    /// To be called after `load`. The moved files can be retrieved through `moved_files`.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToBorrowCategory` - if a category could not be borrowed.
+    /// * `FailedToMoveCategory` - if a file could not be moved, or another file is where it belongs.
+    pub fn fix_directory_layout(&mut self) -> Result<(), Error> {
+        let mut misplaced_files: Vec<(String, String, String, PathBuf)> = Vec::new();
+
+        for (id, category) in self.categories_mapping.iter() {
+            let path = match self.categories_path.get(id) {
+                Some(path) => path,
+                None => continue,
+            };
+
+            match expected_category_path(self.paths.categories_root(), category) {
+                Ok(expected_path) if Path::new(path) != expected_path => {
+                    let name = match category.try_borrow() {
+                        Ok(category) => category.name.clone(),
+                        Err(error) => {
+                            return Err(Error::new(
+                                ErrorKind::FailedToBorrowCategory,
+                                "failed to borrow category",
+                            )
+                            .with_source(error))
+                        }
+                    };
+
+                    misplaced_files.push((id.clone(), name, path.clone(), expected_path));
+                }
+                Ok(_) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        misplaced_files.sort_by(|first, second| first.2.cmp(&second.2));
+
+        for (id, name, path, expected_path) in misplaced_files {
+            let location = SourceLocator::new().locate_category(
+                path.as_str(),
+                Some(id.as_str()),
+                Some(name.as_str()),
+            );
+            let expected_path_string = expected_path.to_string_lossy().to_string();
+
+            if expected_path.exists() {
+                return Err(Error::new(
+                    ErrorKind::FailedToMoveCategory,
+                    format!(
+                        "cannot move category '{}' from '{}' to '{}', given the latter already exists",
+                        name, path, expected_path_string
+                    )
+                    .as_str(),
+                )
+                .with_location(location));
+            }
+
+            let result = match expected_path.parent() {
+                Some(directory) => std::fs::create_dir_all(directory)
+                    .and_then(|_| std::fs::rename(path.as_str(), &expected_path)),
+                None => std::fs::rename(path.as_str(), &expected_path),
+            };
+
+            match result {
+                Ok(_) => (),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToMoveCategory,
+                        format!(
+                            "failed to move category '{}' from '{}' to '{}'",
+                            name, path, expected_path_string
+                        )
+                        .as_str(),
+                    )
+                    .with_source(error)
+                    .with_location(location))
+                }
+            }
+
+            self.categories_path
+                .insert(id.clone(), expected_path_string.clone());
+            self.categories_io.insert(
+                id,
+                Box::new(CategoryFileIO::new(
+                    self.paths.categories_root().to_string(),
+                    expected_path_string.clone(),
+                )),
+            );
+            self.moved_files.push(MovedFile {
+                category: name,
+                from: path,
+                to: expected_path_string,
+            });
+        }
+
+        self.remove_emptied_directories()
+    }
+
+    /// Removes the directories that the moved files have left empty, alongside their ancestors that became
+    /// empty as well, up to the categories' root.
+    ///
+    /// Error kinds:
+    ///
+    /// * `FailedToMoveCategory` - if an empty directory could not be removed.
+    fn remove_emptied_directories(&self) -> Result<(), Error> {
+        let categories_root = Path::new(self.paths.categories_root());
+        let mut directories: Vec<PathBuf> = self
+            .moved_files
+            .iter()
+            .filter_map(|moved_file| Path::new(moved_file.from.as_str()).parent())
+            .map(|directory| directory.to_path_buf())
+            .collect();
+
+        // Deepest directories first, so their parents are empty by the time they are visited.
+        directories.sort_by(|first, second| second.cmp(first));
+        directories.dedup();
+
+        for directory in directories {
+            let mut current = Some(directory.as_path());
+
+            while let Some(directory) = current {
+                if directory == categories_root || !directory.starts_with(categories_root) {
+                    break;
+                }
+
+                let is_empty = match std::fs::read_dir(directory) {
+                    Ok(mut entries) => entries.next().is_none(),
+                    Err(_) => false,
+                };
+
+                if !is_empty {
+                    break;
+                }
+
+                match std::fs::remove_dir(directory) {
+                    Ok(_) => (),
+                    Err(error) => {
+                        return Err(Error::new(
+                            ErrorKind::FailedToMoveCategory,
+                            format!(
+                                "failed to remove directory '{}', left empty by the moved files",
+                                directory.to_string_lossy()
+                            )
+                            .as_str(),
+                        )
+                        .with_source(error))
+                    }
+                }
+
+                current = directory.parent();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Category files moved by the last run.
+    pub fn moved_files(&self) -> &[MovedFile] {
+        self.moved_files.as_slice()
+    }
+
+    /// Ids generated by the last run, regardless of whether they have been written or not.
+    pub fn generated_ids(&self) -> &[GeneratedId] {
+        self.generated_ids.as_slice()
+    }
+
+    fn run_default_validations(&mut self) -> Result<(), Error> {
+        match self.enabled_category_validations() {
+            Ok(category_validations) => match self.enabled_attribute_validations() {
+                Ok(attribute_validations) => self.validate(
+                    category_validations.as_slice(),
+                    attribute_validations.as_slice(),
+                ),
                 Err(error) => Err(error),
             },
             Err(error) => Err(error),
         }
     }
+
+    /// Copies the tracked entries into memory, where the generated ids are kept until they are applied.
+    fn load_tracked_entries(&mut self) -> Result<(), Error> {
+        let category_entries = match self.category_id_tracker_io.read_entries() {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadCategory,
+                    "failed to read categories' entries",
+                )
+                .with_source(error))
+            }
+        };
+
+        let attribute_entries = match self.attribute_tracker_io.read_entries() {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadAttribute,
+                    "failed to read attributes' entries",
+                )
+                .with_source(error))
+            }
+        };
+
+        let option_entries = match self.option_tracker_io.read_entries() {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToReadAttribute,
+                    "failed to read options' entries",
+                )
+                .with_source(error))
+            }
+        };
+
+        // Every tracker is read before any is replaced, so a failure leaves the loaded ones untouched.
+        self.loaded_category_id_tracker_io =
+            Rc::new(CategoryIdTrackerMemoryIO::new(category_entries));
+        self.loaded_attribute_tracker_io =
+            Rc::new(AttributeTrackerMemoryIO::new(attribute_entries));
+        self.loaded_option_tracker_io = Rc::new(OptionTrackerMemoryIO::new(option_entries));
+
+        Ok(())
+    }
+
+    /// Error kinds:
+    ///
+    /// * `PendingChanges` - if `apply_generated_ids` would change any file.
+    /// * `FailedToReadCategory` - if a category's file could not be read back.
+    fn ensure_no_pending_changes(&self) -> Result<(), Error> {
+        let mut changed_files: Vec<String> = Vec::new();
+
+        if !self.pending_category_entries.is_empty() {
+            changed_files.push(self.paths.category_id_tracker().to_string());
+        }
+
+        if self.outdated_attribute_tracker || !self.pending_attribute_entries.is_empty() {
+            changed_files.push(self.paths.attribute_id_tracker().to_string());
+        }
+
+        if !self.pending_option_entries.is_empty() {
+            changed_files.push(self.paths.option_id_tracker().to_string());
+        }
+
+        let mut category_files: Vec<String> = Vec::new();
+
+        for (id, category) in self.categories_mapping.iter() {
+            let path = match self.categories_path.get(id) {
+                Some(path) => path,
+                None => continue,
+            };
+
+            match self.render_category(category) {
+                Ok(expected_content) => match std::fs::read_to_string(path) {
+                    Ok(content) if content == expected_content => (),
+                    Ok(_) => category_files.push(path.clone()),
+                    Err(error) => {
+                        return Err(Error::new(
+                            ErrorKind::FailedToReadCategory,
+                            format!("failed to read category '{}'", path).as_str(),
+                        )
+                        .with_source(error))
+                    }
+                },
+                Err(error) => return Err(error),
+            }
+        }
+
+        category_files.sort();
+        changed_files.extend(category_files);
+
+        if changed_files.is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            ErrorKind::PendingChanges,
+            format!(
+                "{} file(s) would change: {}",
+                changed_files.len(),
+                changed_files.join(", ")
+            )
+            .as_str(),
+        ))
+    }
+
+    /// Content of the category's file as `apply_generated_ids` would write it.
+    fn render_category(&self, category: &Rc<RefCell<Category>>) -> Result<String, Error> {
+        let category = match category.try_borrow() {
+            Ok(category) => category,
+            Err(error) => {
+                return Err(Error::new(
+                    ErrorKind::FailedToBorrowCategory,
+                    "failed to borrow category",
+                )
+                .with_source(error))
+            }
+        };
+
+        let parent = match category.parent.as_ref().and_then(|parent| parent.upgrade()) {
+            Some(parent) => match parent.try_borrow() {
+                Ok(parent) => Some((parent.id.clone(), parent.name.clone())),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::FailedToBorrowCategory,
+                        "failed to borrow category",
+                    )
+                    .with_source(error))
+                }
+            },
+            None => None,
+        };
+
+        let (parent_id, parent_name) = match parent {
+            Some((parent_id, parent_name)) => (Some(parent_id), Some(parent_name)),
+            None => (None, None),
+        };
+
+        // Same shape as the category's io writes it.
+        let source_category = SourceCategory {
+            id: Some(category.id.clone()),
+            parent: parent_id,
+            parent_name,
+            name: category.name.clone(),
+            selectable_as_last: Some(category.selectable_as_last),
+            attributes: Attribute::to_source_attributes(category.attributes.as_slice()),
+        };
+
+        let has_extensions = category
+            .attributes
+            .iter()
+            .any(|attribute| self.attribute_extensions.contains_key(&attribute.id));
+
+        let content = match serde_json::to_string_pretty(&source_category) {
+            Ok(content) if has_extensions => {
+                merge_attribute_extensions(content.as_str(), &self.attribute_extensions)
+            }
+            result => result,
+        };
+
+        match content {
+            Ok(content) => Ok(content),
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToWriteCategory,
+                format!(
+                    "failed to serialize category '{}' with id '{}'",
+                    category.name, category.id
+                )
+                .as_str(),
+            )
+            .with_source(error)),
+        }
+    }
 }
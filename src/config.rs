@@ -1,12 +1,67 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
+use crate::attributes::attribute_tracker_io::AllowedAttributeChange;
+use crate::data_type_widenings::DataTypeWidenings;
+use crate::naming_convention::{NamingConvention, NamingConventions};
+use crate::reserved_keywords::{KeywordMatching, ReservedKeyword, ReservedKeywords};
+use crate::severity::Severity;
+use crate::unit_catalog::UnitCatalog;
+
 #[derive(Deserialize)]
 pub struct Config {
     valid_data_types: Vec<String>,
-    reserved_keywords: Vec<String>,
+    /// Keywords attribute names cannot use.
+    reserved_keywords: Vec<ReservedKeyword>,
+    #[serde(default)]
+    reserved_category_keywords: Vec<ReservedKeyword>,
+    #[serde(default)]
+    reserved_keyword_matching: KeywordMatching,
+    #[serde(default)]
+    deprecated_data_types: HashMap<String, String>,
+    #[serde(default)]
+    validations: HashMap<String, Severity>,
+    #[serde(default)]
+    allowed_files: Vec<String>,
+    #[serde(default)]
+    naming_conventions: NamingConventions,
+    #[serde(default)]
+    unit_catalog: UnitCatalog,
+    #[serde(default)]
+    data_type_widenings: DataTypeWidenings,
+    #[serde(default)]
+    allowed_attribute_changes: HashMap<String, Vec<AllowedAttributeChange>>,
+    #[serde(default)]
+    data_type_formats: HashMap<String, String>,
 }
 
 impl Config {
+    pub fn new(valid_data_types: Vec<String>, reserved_keywords: Vec<String>) -> Config {
+        Config {
+            valid_data_types,
+            reserved_keywords: reserved_keywords
+                .into_iter()
+                .map(ReservedKeyword::Keyword)
+                .collect(),
+            reserved_category_keywords: Vec::new(),
+            reserved_keyword_matching: KeywordMatching::default(),
+            deprecated_data_types: HashMap::new(),
+            validations: HashMap::new(),
+            allowed_files: Vec::new(),
+            naming_conventions: NamingConventions::default(),
+            unit_catalog: UnitCatalog::default(),
+            data_type_widenings: DataTypeWidenings::default(),
+            allowed_attribute_changes: HashMap::new(),
+            data_type_formats: HashMap::new(),
+        }
+    }
+
+    /// Overrides the severity of the validation registered with the given name.
+    pub fn set_validation_severity(&mut self, name: &str, severity: Severity) {
+        self.validations.insert(name.to_string(), severity);
+    }
+
     pub fn valid_data_types(&self) -> Vec<String> {
         let mut valid_data_types_copy: Vec<String> = Vec::new();
 
@@ -17,13 +72,118 @@ impl Config {
         valid_data_types_copy
     }
 
-    pub fn reserved_keywords(&self) -> Vec<String> {
-        let mut reserved_keywords_copy: Vec<String> = Vec::new();
+    /// Keywords attribute names cannot use, alongside how names are compared with them.
+    pub fn reserved_attribute_keywords(&self) -> ReservedKeywords {
+        ReservedKeywords::new(
+            self.reserved_keywords.clone(),
+            self.reserved_keyword_matching,
+        )
+    }
+
+    /// Keywords category names cannot use, alongside how names are compared with them.
+    pub fn reserved_category_keywords(&self) -> ReservedKeywords {
+        ReservedKeywords::new(
+            self.reserved_category_keywords.clone(),
+            self.reserved_keyword_matching,
+        )
+    }
+
+    pub fn reserve_category_keyword(&mut self, reserved_keyword: ReservedKeyword) {
+        self.reserved_category_keywords.push(reserved_keyword);
+    }
 
-        for reserved_keyword in &self.reserved_keywords {
-            reserved_keywords_copy.push(reserved_keyword.clone());
+    pub fn set_reserved_keyword_matching(&mut self, reserved_keyword_matching: KeywordMatching) {
+        self.reserved_keyword_matching = reserved_keyword_matching;
+    }
+
+    /// Explanation of each deprecated data type, with the data type as key.
+    ///
+    /// Deprecated data types are still valid, therefore they must remain within the valid data types.
+    pub fn deprecated_data_types(&self) -> HashMap<String, String> {
+        self.deprecated_data_types.clone()
+    }
+
+    /// Deprecates a data type, explaining e.g. which data type replaces it.
+    pub fn deprecate_data_type(&mut self, data_type: &str, explanation: &str) {
+        self.deprecated_data_types
+            .insert(data_type.to_string(), explanation.to_string());
+    }
+
+    /// Severity set for the validation with the given name, if any.
+    pub fn validation_severity(&self, name: &str) -> Option<Severity> {
+        self.validations.get(name).copied()
+    }
+
+    /// Globs of the files, relative to the categories' root directory, that are allowed besides the
+    /// category definitions, e.g. `**/README.md`.
+    pub fn allowed_files(&self) -> Vec<String> {
+        self.allowed_files.clone()
+    }
+
+    pub fn allow_files(&mut self, glob: &str) {
+        self.allowed_files.push(glob.to_string());
+    }
+
+    /// Naming convention category names must follow, if any.
+    pub fn category_naming_convention(&self) -> Option<NamingConvention> {
+        self.naming_conventions.categories.clone()
+    }
+
+    /// Naming convention attribute names must follow, if any.
+    pub fn attribute_naming_convention(&self) -> Option<NamingConvention> {
+        self.naming_conventions.attributes.clone()
+    }
+
+    pub fn set_naming_conventions(&mut self, naming_conventions: NamingConventions) {
+        self.naming_conventions = naming_conventions;
+    }
+
+    /// Units attributes can use, per data type.
+    pub fn unit_catalog(&self) -> UnitCatalog {
+        self.unit_catalog.clone()
+    }
+
+    pub fn set_unit_catalog(&mut self, unit_catalog: UnitCatalog) {
+        self.unit_catalog = unit_catalog;
+    }
+
+    /// Data type changes that are compatible, therefore allowed for every attribute.
+    pub fn data_type_widenings(&self) -> DataTypeWidenings {
+        self.data_type_widenings.clone()
+    }
+
+    pub fn set_data_type_widenings(&mut self, data_type_widenings: DataTypeWidenings) {
+        self.data_type_widenings = data_type_widenings;
+    }
+
+    /// Changes of the tracked fields of the attribute, identified by its id, that are allowed.
+    pub fn allowed_attribute_changes(&self, attribute_id: &str) -> Vec<AllowedAttributeChange> {
+        match self.allowed_attribute_changes.get(attribute_id) {
+            Some(changes) => changes.clone(),
+            None => Vec::new(),
         }
+    }
+
+    pub fn allow_attribute_change(&mut self, attribute_id: &str, change: AllowedAttributeChange) {
+        self.allowed_attribute_changes
+            .entry(attribute_id.to_string())
+            .or_default()
+            .push(change);
+    }
+
+    /// Regular expression the default values of each data type must match, with the data type's name as
+    /// key, e.g. `^\d{4}-\d{2}-\d{2}$` for `date`.
+    pub fn data_type_formats(&self) -> HashMap<String, String> {
+        self.data_type_formats.clone()
+    }
+
+    pub fn set_data_type_format(&mut self, data_type: &str, format: &str) {
+        self.data_type_formats
+            .insert(data_type.to_string(), format.to_string());
+    }
 
-        reserved_keywords_copy
+    /// Severities set for the validations, with the validation's name as key.
+    pub fn validations(&self) -> &HashMap<String, Severity> {
+        &self.validations
     }
 }
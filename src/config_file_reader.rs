@@ -18,15 +18,13 @@ impl ConfigReader for ConfigFileReader {
     fn read(&self) -> Result<Config, Error> {
         match std::fs::read_to_string(self.config_path.as_str()) {
             Ok(config_json) => match serde_json::de::from_str(config_json.as_str()) {
-                Ok(config) => return Ok(config),
-                Err(error) => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        format!("Failed to deserialize config's JSON: {}", error),
-                    ));
-                }
+                Ok(config) => Ok(config),
+                Err(error) => Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Failed to deserialize config's JSON: {}", error),
+                )),
             },
-            Err(error) => return Err(error),
+            Err(error) => Err(error),
         }
     }
 }
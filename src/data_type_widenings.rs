@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::type_system::{DataType, ENUM};
+
+/// Parameters bounding the values of a data type, which are unbounded if not set.
+const BOUNDING_PARAMETERS: [&str; 2] = ["precision", "max"];
+
+/// Data types each data type can be widened into, as set within the configuration:
+///
+/// ```json
+/// "data_type_widenings": {
+///     "integer": ["decimal", "string"],
+///     "decimal": ["string"],
+///     "list<integer>": ["list<decimal>"]
+/// }
+/// ```
+///
+/// Widenings are not transitive: every allowed change has to be listed.
+///
+/// Data types are compared once parsed, so list items and enum options are compared as well. Parameters bound
+/// the values of a data type: the `precision` or `max` of the data type being widened can be at most the listed
+/// one, while the wider data type's can be no less than the listed one, with parameters that are not set being
+/// unbounded. Therefore widening `integer` into `decimal` does not allow changing it into
+/// `decimal(precision=3,scale=2)`. Any other listed parameter has to be the same.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct DataTypeWidenings {
+    data_types: HashMap<String, Vec<String>>,
+}
+
+/// Side of a widening a data type is compared on.
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    /// Data type being widened, whose bounds can be narrower than the listed ones.
+    From,
+    /// Wider data type, whose bounds can be wider than the listed ones.
+    To,
+}
+
+impl DataTypeWidenings {
+    pub fn new(data_types: HashMap<String, Vec<String>>) -> DataTypeWidenings {
+        DataTypeWidenings { data_types }
+    }
+
+    /// Whether changing from one data type to the other is a widening, therefore a compatible change.
+    pub fn allows(&self, from: &str, to: &str) -> bool {
+        self.data_types.iter().any(|(data_type, wider_data_types)| {
+            matches(data_type, from, Side::From)
+                && wider_data_types
+                    .iter()
+                    .any(|wider_data_type| matches(wider_data_type, to, Side::To))
+        })
+    }
+}
+
+/// Whether the data type matches the one listed within the configuration, compared as they are if either
+/// cannot be parsed.
+fn matches(listed: &str, data_type: &str, side: Side) -> bool {
+    match (DataType::parse(listed), DataType::parse(data_type)) {
+        (Ok(listed), Ok(data_type)) => matches_parsed(&listed, &data_type, side),
+        _ => listed == data_type,
+    }
+}
+
+fn matches_parsed(listed: &DataType, data_type: &DataType, side: Side) -> bool {
+    match (listed, data_type) {
+        (
+            DataType::Named {
+                name: listed_name,
+                parameters: listed_parameters,
+            },
+            DataType::Named { name, parameters },
+        ) => listed_name == name && matches_parameters(listed_parameters, parameters, side),
+        // A listed `enum` refers to any enum, regardless of its options.
+        (DataType::Named { name, parameters }, DataType::Enum { .. }) => {
+            name == ENUM && parameters.is_empty()
+        }
+        (
+            DataType::Enum {
+                options: listed_options,
+            },
+            DataType::Enum { options },
+        ) => match side {
+            Side::From => options.iter().all(|option| listed_options.contains(option)),
+            Side::To => listed_options.iter().all(|option| options.contains(option)),
+        },
+        (DataType::List { item: listed_item }, DataType::List { item }) => {
+            matches_parsed(listed_item, item, side)
+        }
+        _ => false,
+    }
+}
+
+fn matches_parameters(
+    listed_parameters: &[(String, String)],
+    parameters: &[(String, String)],
+    side: Side,
+) -> bool {
+    let listed_value = |name: &str| {
+        listed_parameters
+            .iter()
+            .find(|(listed_name, _)| listed_name == name)
+            .map(|(_, value)| value.as_str())
+    };
+    let value = |name: &str| {
+        parameters
+            .iter()
+            .find(|(parameter_name, _)| parameter_name == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    for bounding_parameter in BOUNDING_PARAMETERS {
+        let within_bound = match (listed_value(bounding_parameter), value(bounding_parameter)) {
+            (None, None) => true,
+            // Unbounded data types are only within the bounds of other unbounded ones.
+            (None, Some(_)) => side == Side::From,
+            (Some(_), None) => side == Side::To,
+            (Some(listed_bound), Some(bound)) => {
+                match (listed_bound.parse::<u64>(), bound.parse::<u64>()) {
+                    (Ok(listed_bound), Ok(bound)) => match side {
+                        Side::From => bound <= listed_bound,
+                        Side::To => bound >= listed_bound,
+                    },
+                    _ => listed_bound == bound,
+                }
+            }
+        };
+
+        if !within_bound {
+            return false;
+        }
+    }
+
+    listed_parameters
+        .iter()
+        .filter(|(name, _)| !BOUNDING_PARAMETERS.contains(&name.as_str()))
+        .all(|(name, listed_value)| value(name.as_str()) == Some(listed_value.as_str()))
+}
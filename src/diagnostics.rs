@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use cooplan_definitions_lib::attribute::Attribute;
+use cooplan_definitions_lib::category::Category;
+use serde::Serialize;
+
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::severity::Severity;
+use crate::source_location::{SourceLocation, SourceLocator};
+
+/// Problem found while validating the definitions, alongside the category and attribute it refers to.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub kind: ErrorKind,
+    pub message: String,
+    pub category_id: Option<String>,
+    pub category_name: Option<String>,
+    pub attribute_id: Option<String>,
+    pub attribute_name: Option<String>,
+    pub details: Option<ErrorDetails>,
+    pub file_path: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl Diagnostic {
+    pub fn new(kind: ErrorKind, message: &str) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            kind,
+            message: message.to_string(),
+            category_id: None,
+            category_name: None,
+            attribute_id: None,
+            attribute_name: None,
+            details: None,
+            file_path: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn for_category(kind: ErrorKind, message: &str, category: &Category) -> Diagnostic {
+        let mut diagnostic = Diagnostic::new(kind, message);
+        diagnostic.category_id = Some(category.id.clone());
+        diagnostic.category_name = Some(category.name.clone());
+
+        diagnostic
+    }
+
+    pub fn for_attribute(
+        kind: ErrorKind,
+        message: &str,
+        category: &Category,
+        attribute: &Attribute,
+    ) -> Diagnostic {
+        let mut diagnostic = Diagnostic::for_category(kind, message, category);
+        diagnostic.attribute_id = Some(attribute.id.clone());
+        diagnostic.attribute_name = Some(attribute.name.clone());
+
+        diagnostic
+    }
+
+    pub fn with_details(mut self, details: Option<ErrorDetails>) -> Diagnostic {
+        self.details = details;
+
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.file_path.as_ref().map(|file_path| SourceLocation {
+            file_path: file_path.clone(),
+            line: self.line,
+            column: self.column,
+        })
+    }
+
+    pub fn set_location(&mut self, location: SourceLocation) {
+        self.file_path = Some(location.file_path);
+        self.line = location.line;
+        self.column = location.column;
+    }
+}
+
+impl From<Error> for Diagnostic {
+    fn from(error: Error) -> Self {
+        Diagnostic::from(&error)
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(error: &Error) -> Self {
+        let mut diagnostic = Diagnostic::new(error.kind, error.full_message().as_str())
+            .with_details(error.details().cloned());
+
+        if let Some(location) = error.location() {
+            diagnostic.set_location(location.clone());
+        }
+
+        diagnostic
+    }
+}
+
+impl From<&Diagnostic> for Error {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        let mut error = Error::new(diagnostic.kind, diagnostic.message.as_str());
+
+        if let Some(details) = &diagnostic.details {
+            error = error.with_details(details.clone());
+        }
+
+        if let Some(location) = diagnostic.location() {
+            error = error.with_location(location);
+        }
+
+        error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Collector of every problem found by the validations, so a single run reports all of them.
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Diagnostics {
+        Diagnostics {
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+
+    pub fn as_slice(&self) -> &[Diagnostic] {
+        self.diagnostics.as_slice()
+    }
+
+    /// Moves the diagnostics of another collector into this one.
+    pub fn append(&mut self, mut diagnostics: Diagnostics) {
+        self.diagnostics.append(&mut diagnostics.diagnostics);
+    }
+
+    /// Sets the severity of every diagnostic collected.
+    pub fn set_severity(&mut self, severity: Severity) {
+        for diagnostic in self.diagnostics.iter_mut() {
+            diagnostic.severity = severity;
+        }
+    }
+
+    /// Number of diagnostics with the given severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+
+    /// Fills the location of the diagnostics that refer to a category but have no location yet, pointing
+    /// to the attribute's definition or, if there is no attribute, to the category's definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories_path` - path of each category's file, with the category's id as key.
+    /// * `source_locator` - locator of the positions within the categories' files.
+    pub fn resolve_locations(
+        &mut self,
+        categories_path: &HashMap<String, String>,
+        source_locator: &mut SourceLocator,
+    ) {
+        for diagnostic in self.diagnostics.iter_mut() {
+            if diagnostic.file_path.is_some() {
+                continue;
+            }
+
+            let file_path = match &diagnostic.category_id {
+                Some(category_id) => match categories_path.get(category_id) {
+                    Some(file_path) => file_path,
+                    None => continue,
+                },
+                None => continue,
+            };
+
+            let location =
+                if diagnostic.attribute_id.is_some() || diagnostic.attribute_name.is_some() {
+                    source_locator.locate_attribute(
+                        file_path,
+                        diagnostic.attribute_id.as_deref(),
+                        diagnostic.attribute_name.as_deref(),
+                    )
+                } else {
+                    source_locator.locate_category(
+                        file_path,
+                        diagnostic.category_id.as_deref(),
+                        diagnostic.category_name.as_deref(),
+                    )
+                };
+
+            diagnostic.set_location(location);
+        }
+    }
+}
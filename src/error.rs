@@ -1,6 +1,10 @@
 use std::fmt;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+use serde::Serialize;
+
+use crate::source_location::SourceLocation;
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
 pub enum ErrorKind {
     MissingId,
     IdNotFound,
@@ -28,12 +32,96 @@ pub enum ErrorKind {
     FailedDataTypeConstantAttributeValidation,
     FailedToReadConfig,
     ReservedKeywordUsedAsName,
+    InvalidArgument,
+    PendingChanges,
+    ValidationFailed,
+    FailedToReport,
+    UnknownValidation,
+    DeprecatedDataType,
+    DuplicatedSiblingName,
+    CategoryCycle,
+    InvalidCategoryTree,
+    MisplacedCategoryFile,
+    FailedToMoveCategory,
+    StrayFile,
+    InvalidCategoryFile,
+    EmptyDirectory,
+    DuplicatedAttributeName,
+    ShadowedAttributeName,
+    NamingConventionViolated,
+    InvalidNamingConvention,
+    UnitNotAllowed,
+    UnknownUnit,
+    UnitChanged,
+    AttributeRenamed,
+    AttributeCategoryChanged,
+    MissingOptions,
+    UnexpectedOptions,
+    DuplicatedOptionName,
+    OptionIdNotTracked,
+    DuplicatedOptionId,
+    OptionRemoved,
+    OptionReused,
+    InvalidConstraint,
+    ConstraintTightened,
+    InvalidDefaultValue,
+}
+
+/// Typed information about an error, so it can be inspected without parsing its message.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ErrorDetails {
+    /// Id of the category or attribute the error refers to.
+    Id { id: String },
+    /// Ids involved in the error, e.g. the ones that have not been tracked.
+    Ids { ids: Vec<String> },
+    /// Name shared by several categories or attributes.
+    Name { name: String, ids: Vec<String> },
+    /// Data type that is not valid.
+    DataType { data_type: String },
+    /// Data type that has changed since it was tracked.
+    DataTypeChange { from: String, to: String },
+    /// Reserved keyword used as a name, alongside why it is reserved.
+    ReservedKeyword {
+        keyword: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        explanation: Option<String>,
+    },
+    /// Unit that is not valid.
+    Unit { unit: String },
+    /// Unit that has changed since it was tracked, empty if there was or is no unit.
+    UnitChange { from: String, to: String },
+    /// Name that has changed since it was tracked.
+    NameChange { from: String, to: String },
+    /// Id of the category an attribute belongs to, that has changed since it was tracked.
+    CategoryChange { from: String, to: String },
+    /// Rules of the naming convention a name does not follow.
+    NamingConvention { violations: Vec<String> },
+    /// Tracked option of an enumerated attribute.
+    Option {
+        id: String,
+        attribute_id: String,
+        name: String,
+    },
+    /// Constraint of an attribute, e.g. `max_length`.
+    Constraint { constraint: String },
+    /// Default value of an attribute, written as JSON, alongside why it is not valid.
+    DefaultValue {
+        value: String,
+        violations: Vec<String>,
+    },
+    /// Path a file is expected at.
+    ExpectedPath { path: String },
 }
 
 #[derive(Debug)]
 pub struct Error {
     pub kind: ErrorKind,
     pub message: String,
+    details: Option<Box<ErrorDetails>>,
+    location: Option<Box<SourceLocation>>,
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    errors: Vec<Error>,
 }
 
 impl Error {
@@ -41,20 +129,124 @@ impl Error {
         Error {
             kind,
             message: message.to_string(),
+            details: None,
+            location: None,
+            source: None,
+            errors: Vec::new(),
         }
     }
 
+    /// Creates an error that groups several errors, e.g. every problem found by a validation run.
+    pub fn aggregate(kind: ErrorKind, message: &str, errors: Vec<Error>) -> Error {
+        let mut error = Error::new(kind, message);
+        error.errors = errors;
+
+        error
+    }
+
+    /// Attaches the underlying error that caused this one.
+    pub fn with_source(
+        mut self,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    ) -> Error {
+        self.source = Some(source.into());
+
+        self
+    }
+
+    pub fn with_details(mut self, details: ErrorDetails) -> Error {
+        self.details = Some(Box::new(details));
+
+        self
+    }
+
+    /// Attaches the location of the file, and optionally the position within it, that caused the error.
+    pub fn with_location(mut self, location: SourceLocation) -> Error {
+        self.location = Some(Box::new(location));
+
+        self
+    }
+
     pub fn kind(&self) -> ErrorKind {
         self.kind
     }
+
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        self.details.as_deref()
+    }
+
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_deref()
+    }
+
+    /// Errors grouped by this one.
+    pub fn errors(&self) -> &[Error] {
+        self.errors.as_slice()
+    }
+
+    /// Message followed by the message of each underlying error, i.e. "failed to read: file not found".
+    pub fn full_message(&self) -> String {
+        let mut message = self.message.clone();
+        let mut source = std::error::Error::source(self);
+
+        while let Some(error) = source {
+            message.push_str(format!(": {}", error).as_str());
+            source = error.source();
+        }
+
+        message
+    }
 }
 
 impl fmt::Display for Error {
+    /// Writes the message and the location, if any.
+    ///
+    /// The alternate form (`{:#}`) also writes the underlying errors and the grouped errors.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = if f.alternate() {
+            self.full_message()
+        } else {
+            self.message.clone()
+        };
+
+        match &self.location {
+            Some(location) => write!(f, "{} (at {})", message, location),
+            None => write!(f, "{}", message),
+        }?;
+
+        if f.alternate() {
+            for error in self.errors.as_slice() {
+                write!(f, "\n    {:#}", error)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
+
+/// Error of the definitions library, kept as the source of the errors converted from it.
+#[derive(Debug)]
+struct DefinitionsLibError {
+    message: String,
+}
+
+impl fmt::Display for DefinitionsLibError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.message)
     }
 }
 
+impl std::error::Error for DefinitionsLibError {}
+
 impl From<cooplan_definitions_lib::error::Error> for Error {
     fn from(error: cooplan_definitions_lib::error::Error) -> Self {
         let kind: ErrorKind = match error.kind() {
@@ -73,9 +265,12 @@ impl From<cooplan_definitions_lib::error::Error> for Error {
             }
         };
 
-        Error {
+        Error::new(
             kind,
+            format!("definitions library failed: {}", error.message).as_str(),
+        )
+        .with_source(DefinitionsLibError {
             message: error.message.clone(),
-        }
+        })
     }
 }
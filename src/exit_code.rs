@@ -0,0 +1,58 @@
+use crate::diagnostics::Diagnostics;
+use crate::error::{Error, ErrorKind};
+use crate::severity::Severity;
+
+/// Status the command line tool exits with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExitCode {
+    /// No problem has been found, or only warnings that are not denied.
+    Success = 0,
+    /// Problems with `Error` severity have been found, the definitions could not be read or placed within
+    /// their tree, or files would change when checking.
+    Errors = 1,
+    /// Only warnings have been found and warnings are denied.
+    DeniedWarnings = 2,
+    /// The validations could not be run for reasons other than the definitions, e.g. the configuration could
+    /// not be read.
+    InternalFailure = 3,
+}
+
+impl ExitCode {
+    /// Exit code of a run, given its result and the diagnostics it collected.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - result of the run.
+    /// * `diagnostics` - problems found by the run.
+    /// * `deny_warnings` - whether warnings fail the run.
+    pub fn from_run(
+        result: &Result<(), Error>,
+        diagnostics: &Diagnostics,
+        deny_warnings: bool,
+    ) -> ExitCode {
+        match result {
+            Ok(_) => {
+                if deny_warnings && diagnostics.count(Severity::Warning) > 0 {
+                    ExitCode::DeniedWarnings
+                } else {
+                    ExitCode::Success
+                }
+            }
+            Err(error) => match error.kind() {
+                ErrorKind::ValidationFailed | ErrorKind::PendingChanges => ExitCode::Errors,
+                // Problems within the definitions that prevent the validations from running.
+                ErrorKind::InvalidCategoryFile
+                | ErrorKind::InvalidCategoryTree
+                | ErrorKind::ParentNotFound
+                | ErrorKind::DuplicatedName
+                | ErrorKind::CategoryCycle
+                | ErrorKind::InvalidDataType => ExitCode::Errors,
+                _ => ExitCode::InternalFailure,
+            },
+        }
+    }
+
+    pub fn code(&self) -> i32 {
+        *self as i32
+    }
+}
@@ -0,0 +1,63 @@
+/// Matches paths, relative and separated by '/', against glob patterns:
+///
+/// * `?` - any character except '/'.
+/// * `*` - any sequence of characters except '/'.
+/// * `**` - any sequence of characters, including '/'. `**/` also matches no directory at all.
+///
+/// Patterns without any '/' are matched against the file's name, wherever the file is, e.g. `README.md`
+/// matches both `README.md` and `food/README.md`.
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    let path: Vec<char> = path.chars().collect();
+
+    if pattern.contains('/') {
+        let pattern: Vec<char> = pattern.chars().collect();
+
+        return matches_from(pattern.as_slice(), path.as_slice());
+    }
+
+    let file_name_start = path
+        .iter()
+        .rposition(|character| *character == '/')
+        .map_or(0, |position| position + 1);
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    matches_from(pattern.as_slice(), &path[file_name_start..])
+}
+
+fn matches_from(pattern: &[char], path: &[char]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some('*') if pattern.get(1) == Some(&'*') => {
+            let rest = &pattern[2..];
+
+            if rest.first() == Some(&'/') {
+                let rest = &rest[1..];
+
+                matches_from(rest, path)
+                    || (0..path.len())
+                        .any(|index| path[index] == '/' && matches_from(rest, &path[index + 1..]))
+            } else {
+                (0..=path.len()).any(|index| matches_from(rest, &path[index..]))
+            }
+        }
+        Some('*') => {
+            let rest = &pattern[1..];
+
+            for index in 0..=path.len() {
+                if matches_from(rest, &path[index..]) {
+                    return true;
+                }
+
+                if index < path.len() && path[index] == '/' {
+                    return false;
+                }
+            }
+
+            false
+        }
+        Some('?') => !path.is_empty() && path[0] != '/' && matches_from(&pattern[1..], &path[1..]),
+        Some(character) => {
+            path.first() == Some(character) && matches_from(&pattern[1..], &path[1..])
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Validator of Cooplan's product definitions.
+//!
+//! Besides the command line tool, the validations can be run programmatically through `CI`:
+//!
+//! ```no_run
+//! use cooplan_definitions_validator::{Paths, CI};
+//!
+//! let mut ci = CI::try_new(Paths::default()).unwrap();
+//! ci.load().unwrap();
+//!
+//! let category_validations = ci.enabled_category_validations().unwrap();
+//! let attribute_validations = ci.enabled_attribute_validations().unwrap();
+//!
+//! if ci
+//!     .validate(category_validations.as_slice(), attribute_validations.as_slice())
+//!     .is_err()
+//! {
+//!     for diagnostic in ci.diagnostics().as_slice() {
+//!         println!("{}", diagnostic);
+//!     }
+//! }
+//!
+//! // Optionally, write the ids generated while loading.
+//! ci.apply_generated_ids().unwrap();
+//! ```
+
+pub mod arguments;
+pub mod attributes;
+pub mod categories;
+pub mod ci;
+pub mod config;
+pub mod config_file_reader;
+pub mod config_reader;
+pub mod data_type_widenings;
+pub mod diagnostics;
+pub mod error;
+pub mod exit_code;
+pub mod glob;
+pub mod naming_convention;
+pub mod paths;
+pub mod reporters;
+pub mod reserved_keywords;
+pub mod severity;
+pub mod source_location;
+#[cfg(test)]
+mod tests;
+pub mod type_system;
+pub mod unit_catalog;
+pub mod validation_registry;
+
+pub use ci::{GeneratedId, MovedFile, CI};
+pub use config::Config;
+pub use diagnostics::{Diagnostic, Diagnostics};
+pub use error::{Error, ErrorDetails, ErrorKind};
+pub use exit_code::ExitCode;
+pub use paths::Paths;
+pub use severity::Severity;
+pub use validation_registry::ValidationRegistry;
@@ -1,27 +1,185 @@
-use ci::CI;
+use cooplan_definitions_validator::arguments::{Arguments, Command, USAGE};
+use cooplan_definitions_validator::reporters::report_format::ReportFormat;
+use cooplan_definitions_validator::{
+    Diagnostic, Diagnostics, Error, ErrorKind, ExitCode, GeneratedId, Severity, CI,
+};
 
-mod attributes;
-mod categories;
-mod error;
-mod tests;
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let arguments = match Arguments::parse(args.as_slice()) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            println!("Error: {:#}\n\n{}", error, USAGE);
+            std::process::exit(ExitCode::InternalFailure.code());
+        }
+    };
 
-pub mod ci;
-mod config;
-mod config_file_reader;
-mod config_reader;
+    if arguments.command() == Command::Help {
+        println!("{}", USAGE);
+        return;
+    }
 
-fn main() {
-    match CI::try_new() {
-        Ok(mut validator) => match validator.run_ci_logic() {
-            Ok(_) => (),
-            Err(error) => {
-                println!("Error: {}", error);
-                std::process::exit(1);
+    let (result, diagnostics) = match CI::try_new(arguments.paths().clone()) {
+        Ok(mut validator) => {
+            validator.set_fix_layout(arguments.fix_layout());
+
+            let result = match arguments.command() {
+                Command::Validate => validator.run_ci_logic(),
+                Command::GenerateIds => validator.run_generate_ids_logic(),
+                Command::Check => validator.run_check_logic(),
+                Command::Help => Ok(()),
+            };
+
+            print_moved_files(&validator, &arguments);
+            print_generated_ids(&validator, &arguments);
+
+            (result, validator.diagnostics().clone())
+        }
+        Err(error) => (Err(error), Diagnostics::new()),
+    };
+
+    let mut diagnostics = diagnostics;
+    let mut error_reported = false;
+
+    // Failures that prevented the validations from running are reported as well, so the report is
+    // never empty when the run fails.
+    if let Err(error) = &result {
+        if diagnostics.count(Severity::Error) == 0 && error.kind() != ErrorKind::PendingChanges {
+            // Aggregated errors are reported one by one, so each keeps its own location.
+            if error.errors().is_empty() {
+                diagnostics.push(Diagnostic::from(error));
+            } else {
+                for inner_error in error.errors() {
+                    diagnostics.push(Diagnostic::from(inner_error));
+                }
             }
-        },
+
+            error_reported = true;
+        }
+    }
+
+    match write_report(&arguments, &diagnostics) {
+        Ok(_) => (),
+        Err(error) => {
+            print_status(&arguments, format!("Error: {:#}", error).as_str());
+            std::process::exit(ExitCode::InternalFailure.code());
+        }
+    }
+
+    let exit_code = ExitCode::from_run(&result, &diagnostics, arguments.deny_warnings());
+
+    match result {
+        Ok(_) => {
+            if exit_code == ExitCode::DeniedWarnings {
+                print_status(
+                    &arguments,
+                    format!(
+                        "Error: {} warning(s) found while warnings are denied",
+                        diagnostics.count(Severity::Warning)
+                    )
+                    .as_str(),
+                );
+            }
+        }
         Err(error) => {
-            println!("Error: {}", error);
-            std::process::exit(1);
+            if arguments.format() != ReportFormat::Text || !error_reported {
+                print_status(
+                    &arguments,
+                    format!("Error: {}", error.full_message()).as_str(),
+                );
+            }
         }
     }
+
+    std::process::exit(exit_code.code());
+}
+
+fn write_report(arguments: &Arguments, diagnostics: &Diagnostics) -> Result<(), Error> {
+    match arguments
+        .format()
+        .reporter(arguments.sarif_base())
+        .report(diagnostics)
+    {
+        Ok(report) => match arguments.output() {
+            Some(output) => match std::fs::write(output, report) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(Error::new(
+                    ErrorKind::FailedToReport,
+                    format!("failed to write report to '{}'", output).as_str(),
+                )
+                .with_source(error)),
+            },
+            None => {
+                print!("{}", report);
+
+                if arguments.format() != ReportFormat::Text {
+                    println!();
+                }
+
+                Ok(())
+            }
+        },
+        Err(error) => Err(error),
+    }
+}
+
+/// Prints messages that are not part of the report, keeping the standard output parsable for
+/// machine-readable formats.
+fn print_status(arguments: &Arguments, message: &str) {
+    match arguments.format() {
+        ReportFormat::Text => println!("{}", message),
+        _ => eprintln!("{}", message),
+    }
+}
+
+fn print_moved_files(validator: &CI, arguments: &Arguments) {
+    for moved_file in validator.moved_files() {
+        print_status(
+            arguments,
+            format!(
+                "moved category '{}' from '{}' to '{}'",
+                moved_file.category, moved_file.from, moved_file.to
+            )
+            .as_str(),
+        );
+    }
+}
+
+fn print_generated_ids(validator: &CI, arguments: &Arguments) {
+    let verb = match arguments.command() {
+        Command::Check => "would be generated",
+        _ => "generated",
+    };
+
+    for generated_id in validator.generated_ids() {
+        let message = match generated_id {
+            GeneratedId {
+                category,
+                attribute: Some(attribute),
+                option: Some(option),
+                id,
+            } => format!(
+                "id '{}' {} for option '{}' of attribute '{}' of category '{}'",
+                id, verb, option, attribute, category
+            ),
+            GeneratedId {
+                category,
+                attribute: Some(attribute),
+                option: None,
+                id,
+            } => format!(
+                "id '{}' {} for attribute '{}' of category '{}'",
+                id, verb, attribute, category
+            ),
+            GeneratedId {
+                category,
+                attribute: None,
+                id,
+                ..
+            } => format!("id '{}' {} for category '{}'", id, verb, category),
+        };
+
+        print_status(arguments, message.as_str());
+    }
 }
@@ -0,0 +1,144 @@
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::error::{Error, ErrorKind};
+
+/// Predefined naming conventions.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+pub enum NamingPreset {
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+}
+
+impl NamingPreset {
+    fn name(&self) -> &str {
+        match self {
+            NamingPreset::SnakeCase => "snake_case",
+            NamingPreset::KebabCase => "kebab-case",
+            NamingPreset::CamelCase => "camelCase",
+            NamingPreset::PascalCase => "PascalCase",
+        }
+    }
+
+    fn pattern(&self) -> &str {
+        match self {
+            NamingPreset::SnakeCase => "^[a-z][a-z0-9]*(_[a-z0-9]+)*$",
+            NamingPreset::KebabCase => "^[a-z][a-z0-9]*(-[a-z0-9]+)*$",
+            NamingPreset::CamelCase => "^[a-z][a-zA-Z0-9]*$",
+            NamingPreset::PascalCase => "^[A-Z][a-zA-Z0-9]*$",
+        }
+    }
+}
+
+/// Convention names must follow, as set within the configuration. Every rule is optional:
+///
+/// ```json
+/// {
+///     "preset": "snake_case",
+///     "pattern": "^[a-z]",
+///     "max_length": 32,
+///     "charset": "a-z0-9_"
+/// }
+/// ```
+///
+/// * `preset` - predefined convention: `snake_case`, `kebab-case`, `camelCase` or `PascalCase`.
+/// * `pattern` - regular expression names must match.
+/// * `max_length` - maximum number of characters.
+/// * `charset` - characters names are allowed to contain, as within a regular expression's brackets.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct NamingConvention {
+    #[serde(default)]
+    pub preset: Option<NamingPreset>,
+    #[serde(default)]
+    pub pattern: Option<String>,
+    #[serde(default)]
+    pub max_length: Option<usize>,
+    #[serde(default)]
+    pub charset: Option<String>,
+}
+
+/// Naming conventions of categories and attributes.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct NamingConventions {
+    #[serde(default)]
+    pub categories: Option<NamingConvention>,
+    #[serde(default)]
+    pub attributes: Option<NamingConvention>,
+}
+
+/// Naming convention whose regular expressions have been compiled.
+pub struct NamingRules {
+    patterns: Vec<(String, Regex)>,
+    max_length: Option<usize>,
+}
+
+impl NamingRules {
+    /// Error kinds:
+    ///
+    /// * `InvalidNamingConvention` - if the pattern or the charset are not valid regular expressions.
+    pub fn try_new(convention: &NamingConvention) -> Result<NamingRules, Error> {
+        let mut patterns: Vec<(String, String)> = Vec::new();
+
+        if let Some(preset) = convention.preset {
+            patterns.push((
+                format!("does not follow {}", preset.name()),
+                preset.pattern().to_string(),
+            ));
+        }
+
+        if let Some(pattern) = &convention.pattern {
+            patterns.push((format!("does not match '{}'", pattern), pattern.clone()));
+        }
+
+        if let Some(charset) = &convention.charset {
+            patterns.push((
+                format!("contains characters other than '{}'", charset),
+                format!("^[{}]*$", charset),
+            ));
+        }
+
+        let mut compiled_patterns: Vec<(String, Regex)> = Vec::new();
+
+        for (violation, pattern) in patterns {
+            match Regex::new(pattern.as_str()) {
+                Ok(regex) => compiled_patterns.push((violation, regex)),
+                Err(error) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidNamingConvention,
+                        format!("naming convention's '{}' is not valid", pattern).as_str(),
+                    )
+                    .with_source(error))
+                }
+            }
+        }
+
+        Ok(NamingRules {
+            patterns: compiled_patterns,
+            max_length: convention.max_length,
+        })
+    }
+
+    /// Rules the name does not follow, e.g. "does not follow snake_case".
+    pub fn violations(&self, name: &str) -> Vec<String> {
+        let mut violations: Vec<String> = self
+            .patterns
+            .iter()
+            .filter(|(_, regex)| !regex.is_match(name))
+            .map(|(violation, _)| violation.clone())
+            .collect();
+
+        if let Some(max_length) = self.max_length {
+            if name.chars().count() > max_length {
+                violations.push(format!("is longer than {} characters", max_length));
+            }
+        }
+
+        violations
+    }
+}
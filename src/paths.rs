@@ -0,0 +1,95 @@
+const DEFAULT_CONFIG_PATH: &str = "./config.json";
+const DEFAULT_CATEGORIES_ROOT: &str = "./categories/";
+const DEFAULT_CATEGORY_ID_TRACKER_PATH: &str = "./category_id_tracker.csv";
+const DEFAULT_ATTRIBUTE_ID_TRACKER_PATH: &str = "./attribute_id_tracker.csv";
+const DEFAULT_OPTION_ID_TRACKER_PATH: &str = "./option_id_tracker.csv";
+
+/// Locations of the files and directories the validator works with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paths {
+    config: String,
+    categories_root: String,
+    category_id_tracker: String,
+    attribute_id_tracker: String,
+    option_id_tracker: String,
+}
+
+impl Paths {
+    pub fn new(
+        config: &str,
+        categories_root: &str,
+        category_id_tracker: &str,
+        attribute_id_tracker: &str,
+    ) -> Paths {
+        let mut paths = Paths::default();
+
+        paths.set_config(config);
+        paths.set_categories_root(categories_root);
+        paths.set_category_id_tracker(category_id_tracker);
+        paths.set_attribute_id_tracker(attribute_id_tracker);
+
+        paths
+    }
+
+    pub fn config(&self) -> &str {
+        self.config.as_str()
+    }
+
+    pub fn categories_root(&self) -> &str {
+        self.categories_root.as_str()
+    }
+
+    pub fn category_id_tracker(&self) -> &str {
+        self.category_id_tracker.as_str()
+    }
+
+    pub fn attribute_id_tracker(&self) -> &str {
+        self.attribute_id_tracker.as_str()
+    }
+
+    pub fn option_id_tracker(&self) -> &str {
+        self.option_id_tracker.as_str()
+    }
+
+    pub fn set_config(&mut self, config: &str) {
+        self.config = config.to_string();
+    }
+
+    /// Sets the root directory of the categories.
+    ///
+    /// The root always ends with a '/', given that the parent of each category is deduced by trimming
+    /// the root from the category's path.
+    pub fn set_categories_root(&mut self, categories_root: &str) {
+        let mut root = categories_root.to_string();
+
+        if !root.ends_with('/') {
+            root.push('/');
+        }
+
+        self.categories_root = root;
+    }
+
+    pub fn set_category_id_tracker(&mut self, category_id_tracker: &str) {
+        self.category_id_tracker = category_id_tracker.to_string();
+    }
+
+    pub fn set_attribute_id_tracker(&mut self, attribute_id_tracker: &str) {
+        self.attribute_id_tracker = attribute_id_tracker.to_string();
+    }
+
+    pub fn set_option_id_tracker(&mut self, option_id_tracker: &str) {
+        self.option_id_tracker = option_id_tracker.to_string();
+    }
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            config: DEFAULT_CONFIG_PATH.to_string(),
+            categories_root: DEFAULT_CATEGORIES_ROOT.to_string(),
+            category_id_tracker: DEFAULT_CATEGORY_ID_TRACKER_PATH.to_string(),
+            attribute_id_tracker: DEFAULT_ATTRIBUTE_ID_TRACKER_PATH.to_string(),
+            option_id_tracker: DEFAULT_OPTION_ID_TRACKER_PATH.to_string(),
+        }
+    }
+}
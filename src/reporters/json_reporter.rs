@@ -0,0 +1,27 @@
+use crate::diagnostics::Diagnostics;
+use crate::error::{Error, ErrorKind};
+
+use super::reporter::Reporter;
+
+/// Machine-readable report: a JSON array containing every diagnostic.
+#[derive(Default)]
+pub struct JsonReporter {}
+
+impl JsonReporter {
+    pub fn new() -> JsonReporter {
+        JsonReporter {}
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn report(&self, diagnostics: &Diagnostics) -> Result<String, Error> {
+        match serde_json::to_string_pretty(diagnostics.as_slice()) {
+            Ok(report) => Ok(report),
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToReport,
+                "failed to serialize diagnostics",
+            )
+            .with_source(error)),
+        }
+    }
+}
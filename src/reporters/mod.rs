@@ -0,0 +1,5 @@
+pub mod json_reporter;
+pub mod report_format;
+pub mod reporter;
+pub mod sarif_reporter;
+pub mod text_reporter;
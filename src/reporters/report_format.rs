@@ -0,0 +1,34 @@
+use super::json_reporter::JsonReporter;
+use super::reporter::Reporter;
+use super::sarif_reporter::SarifReporter;
+use super::text_reporter::TextReporter;
+
+/// Format in which the diagnostics are reported.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+impl ReportFormat {
+    pub fn try_from_name(name: &str) -> Option<ReportFormat> {
+        match name {
+            "text" => Some(ReportFormat::Text),
+            "json" => Some(ReportFormat::Json),
+            "sarif" => Some(ReportFormat::Sarif),
+            _ => None,
+        }
+    }
+
+    /// Reporter of the format, whose locations are relative to the base directory if the format requires it.
+    pub fn reporter(&self, base_directory: &str) -> Box<dyn Reporter> {
+        match self {
+            ReportFormat::Text => Box::new(TextReporter::new()),
+            ReportFormat::Json => Box::new(JsonReporter::new()),
+            ReportFormat::Sarif => {
+                Box::new(SarifReporter::new().with_base_directory(base_directory))
+            }
+        }
+    }
+}
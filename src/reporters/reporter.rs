@@ -0,0 +1,7 @@
+use crate::diagnostics::Diagnostics;
+use crate::error::Error;
+
+/// Renders the diagnostics of a validation run into a report.
+pub trait Reporter {
+    fn report(&self, diagnostics: &Diagnostics) -> Result<String, Error>;
+}
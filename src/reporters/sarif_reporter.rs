@@ -0,0 +1,236 @@
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorKind};
+use crate::severity::Severity;
+
+use super::reporter::Reporter;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "cooplan-definitions-validator";
+const TOOL_INFORMATION_URI: &str = "https://github.com/simple0x47/cooplan-definitions-validator";
+const URI_BASE_ID: &str = "SRCROOT";
+const DEFAULT_BASE_DIRECTORY: &str = ".";
+
+/// SARIF 2.1.0 report, so code scanning tools can annotate the offending category files.
+///
+/// Each `ErrorKind` found is reported as a rule, and each diagnostic as a result of that rule. Locations
+/// are relative to the base directory, i.e. the repository's root, which is the current directory by default
+/// and is reported as the `SRCROOT` base of every location.
+pub struct SarifReporter {
+    base_directory: String,
+}
+
+impl SarifReporter {
+    pub fn new() -> SarifReporter {
+        SarifReporter {
+            base_directory: DEFAULT_BASE_DIRECTORY.to_string(),
+        }
+    }
+
+    /// Sets the directory the locations are relative to.
+    pub fn with_base_directory(mut self, base_directory: &str) -> SarifReporter {
+        self.base_directory = base_directory.to_string();
+        self
+    }
+
+    /// URI of the base directory, ending with a '/' as SARIF requires.
+    fn base_uri(&self) -> String {
+        let path = to_uri_path(&absolute_path(self.base_directory.as_str()));
+
+        // Paths starting with a drive letter, e.g. 'C:/defs', still need the URI's path to start with a '/'.
+        let mut uri = if path.starts_with('/') {
+            format!("file://{}", encode_uri_path(path.as_str()))
+        } else {
+            format!("file:///{}", encode_uri_path(path.as_str()))
+        };
+
+        if !uri.ends_with('/') {
+            uri.push('/');
+        }
+
+        uri
+    }
+
+    /// URI of the file relative to the base directory, with '..' segments if it is not within it.
+    fn relative_uri(&self, file_path: &str) -> String {
+        let base_directory = absolute_path(self.base_directory.as_str());
+        let file_path = absolute_path(file_path);
+
+        let base_components: Vec<Component> = base_directory.components().collect();
+        let file_components: Vec<Component> = file_path.components().collect();
+        let common_length = base_components
+            .iter()
+            .zip(file_components.iter())
+            .take_while(|(base_component, file_component)| base_component == file_component)
+            .count();
+
+        let mut relative_path = PathBuf::new();
+
+        for _ in common_length..base_components.len() {
+            relative_path.push("..");
+        }
+
+        for component in &file_components[common_length..] {
+            relative_path.push(component.as_os_str());
+        }
+
+        encode_uri_path(to_uri_path(&relative_path).as_str())
+    }
+
+    fn rules(&self, diagnostics: &Diagnostics) -> Vec<Value> {
+        let mut kinds: Vec<ErrorKind> = Vec::new();
+
+        for diagnostic in diagnostics.as_slice() {
+            if !kinds.contains(&diagnostic.kind) {
+                kinds.push(diagnostic.kind);
+            }
+        }
+
+        kinds
+            .iter()
+            .map(|kind| {
+                json!({
+                    "id": format!("{:?}", kind),
+                    "name": format!("{:?}", kind),
+                })
+            })
+            .collect()
+    }
+
+    fn result(&self, diagnostic: &Diagnostic) -> Value {
+        let mut result = json!({
+            "ruleId": format!("{:?}", diagnostic.kind),
+            "level": match diagnostic.severity {
+                Severity::Warning => "warning",
+                _ => "error",
+            },
+            "message": {
+                "text": diagnostic.message,
+            },
+            "properties": {
+                "categoryId": diagnostic.category_id,
+                "categoryName": diagnostic.category_name,
+                "attributeId": diagnostic.attribute_id,
+                "attributeName": diagnostic.attribute_name,
+            },
+        });
+
+        if let Some(location) = diagnostic.location() {
+            let mut physical_location = json!({
+                "artifactLocation": {
+                    "uri": self.relative_uri(location.file_path.as_str()),
+                    "uriBaseId": URI_BASE_ID,
+                },
+            });
+
+            if let Some(line) = location.line {
+                physical_location["region"] = json!({
+                    "startLine": line,
+                    "startColumn": location.column.unwrap_or(1),
+                });
+            }
+
+            result["locations"] = json!([{ "physicalLocation": physical_location }]);
+        }
+
+        result
+    }
+}
+
+impl Reporter for SarifReporter {
+    fn report(&self, diagnostics: &Diagnostics) -> Result<String, Error> {
+        let results: Vec<Value> = diagnostics
+            .as_slice()
+            .iter()
+            .map(|diagnostic| self.result(diagnostic))
+            .collect();
+
+        let sarif = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": TOOL_NAME,
+                        "informationUri": TOOL_INFORMATION_URI,
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": self.rules(diagnostics),
+                    },
+                },
+                "originalUriBaseIds": {
+                    URI_BASE_ID: {
+                        "uri": self.base_uri(),
+                    },
+                },
+                "results": results,
+            }],
+        });
+
+        match serde_json::to_string_pretty(&sarif) {
+            Ok(report) => Ok(report),
+            Err(error) => Err(Error::new(
+                ErrorKind::FailedToReport,
+                "failed to serialize SARIF report",
+            )
+            .with_source(error)),
+        }
+    }
+}
+
+impl Default for SarifReporter {
+    fn default() -> Self {
+        SarifReporter::new()
+    }
+}
+
+/// Absolute form of the path, relative paths being resolved from the current directory, without any '.' or
+/// '..' component.
+fn absolute_path(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        match std::env::current_dir() {
+            Ok(current_directory) => current_directory.join(path),
+            Err(_) => path.to_path_buf(),
+        }
+    };
+
+    let mut normalized_path = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => {
+                normalized_path.pop();
+            }
+            _ => normalized_path.push(component.as_os_str()),
+        }
+    }
+
+    normalized_path
+}
+
+/// Path with '/' as separator, as URIs require.
+fn to_uri_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Percent-encodes the characters of the path that URIs do not allow, keeping its separators.
+fn encode_uri_path(path: &str) -> String {
+    let mut encoded_path = String::new();
+
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+            encoded_path.push(byte as char);
+        } else {
+            encoded_path.push_str(format!("%{:02X}", byte).as_str());
+        }
+    }
+
+    encoded_path
+}
@@ -0,0 +1,36 @@
+use crate::diagnostics::Diagnostics;
+use crate::error::Error;
+
+use super::reporter::Reporter;
+
+/// Human-readable report, with one entry per diagnostic.
+#[derive(Default)]
+pub struct TextReporter {}
+
+impl TextReporter {
+    pub fn new() -> TextReporter {
+        TextReporter {}
+    }
+}
+
+impl Reporter for TextReporter {
+    fn report(&self, diagnostics: &Diagnostics) -> Result<String, Error> {
+        let mut report = String::new();
+
+        for diagnostic in diagnostics.as_slice() {
+            report.push_str(
+                format!(
+                    "{} [{:?}]: {}\n",
+                    diagnostic.severity, diagnostic.kind, diagnostic
+                )
+                .as_str(),
+            );
+
+            if let Some(location) = diagnostic.location() {
+                report.push_str(format!("    at {}\n", location).as_str());
+            }
+        }
+
+        Ok(report)
+    }
+}
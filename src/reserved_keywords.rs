@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+/// How names are compared with the reserved keywords.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeywordMatching {
+    /// Names must be equal to the keyword.
+    Exact,
+    /// Names must be equal to the keyword, regardless of their case.
+    #[default]
+    CaseInsensitive,
+    /// Names must be equal to the keyword, regardless of their case and of the separators `_`, `-` and
+    /// ' ', e.g. `Created-At` matches `created_at`.
+    Normalized,
+}
+
+impl KeywordMatching {
+    fn normalize(&self, name: &str) -> String {
+        match self {
+            KeywordMatching::Exact => name.to_string(),
+            KeywordMatching::CaseInsensitive => name.to_lowercase(),
+            KeywordMatching::Normalized => name
+                .chars()
+                .filter(|character| !matches!(character, '_' | '-' | ' '))
+                .collect::<String>()
+                .to_lowercase(),
+        }
+    }
+}
+
+/// Keyword that cannot be used as a name, optionally explaining why, as set within the configuration:
+///
+/// ```json
+/// ["version", { "keyword": "type", "explanation": "the product's type is set by its category" }]
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum ReservedKeyword {
+    Keyword(String),
+    Explained {
+        keyword: String,
+        explanation: String,
+    },
+}
+
+impl ReservedKeyword {
+    pub fn keyword(&self) -> &str {
+        match self {
+            ReservedKeyword::Keyword(keyword) => keyword.as_str(),
+            ReservedKeyword::Explained { keyword, .. } => keyword.as_str(),
+        }
+    }
+
+    pub fn explanation(&self) -> Option<&str> {
+        match self {
+            ReservedKeyword::Keyword(_) => None,
+            ReservedKeyword::Explained { explanation, .. } => Some(explanation.as_str()),
+        }
+    }
+}
+
+/// Reserved keywords alongside how names are compared with them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReservedKeywords {
+    keywords: Vec<ReservedKeyword>,
+    matching: KeywordMatching,
+}
+
+impl ReservedKeywords {
+    pub fn new(keywords: Vec<ReservedKeyword>, matching: KeywordMatching) -> ReservedKeywords {
+        ReservedKeywords { keywords, matching }
+    }
+
+    /// Reserved keyword the name matches, if any.
+    pub fn find(&self, name: &str) -> Option<&ReservedKeyword> {
+        let normalized_name = self.matching.normalize(name);
+
+        self.keywords
+            .iter()
+            .find(|keyword| self.matching.normalize(keyword.keyword()) == normalized_name)
+    }
+}
+
+impl From<Vec<String>> for ReservedKeywords {
+    /// Keywords without explanations, compared regardless of their case.
+    fn from(keywords: Vec<String>) -> Self {
+        ReservedKeywords::new(
+            keywords.into_iter().map(ReservedKeyword::Keyword).collect(),
+            KeywordMatching::default(),
+        )
+    }
+}
+
+/// Describes the keyword, alongside its explanation if any, e.g. "type (the product's type is set by
+/// its category)".
+pub fn describe_reserved_keyword(reserved_keyword: &ReservedKeyword) -> String {
+    match reserved_keyword.explanation() {
+        Some(explanation) => format!("{} ({})", reserved_keyword.keyword(), explanation),
+        None => reserved_keyword.keyword().to_string(),
+    }
+}
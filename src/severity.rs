@@ -0,0 +1,26 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// How a validation's problems are treated.
+///
+/// * `Error` - the problems fail the validation.
+/// * `Warning` - the problems are reported, but do not fail the validation.
+/// * `Off` - the validation is not run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Off,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "Error"),
+            Severity::Warning => write!(f, "Warning"),
+            Severity::Off => write!(f, "Off"),
+        }
+    }
+}
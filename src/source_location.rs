@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+
+/// Location, within a file, where a problem has been found.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SourceLocation {
+    pub file_path: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl SourceLocation {
+    pub fn new(file_path: &str) -> SourceLocation {
+        SourceLocation {
+            file_path: file_path.to_string(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn with_position(file_path: &str, line: usize, column: usize) -> SourceLocation {
+        SourceLocation {
+            file_path: file_path.to_string(),
+            line: Some(line),
+            column: Some(column),
+        }
+    }
+
+    /// Builds the location from messages that mention the position, such as JSON parsing errors like
+    /// "expected `,` or `}` at line 3 column 5".
+    pub fn from_message(file_path: &str, message: &str) -> SourceLocation {
+        let line = number_after(message, "line ");
+        let column = number_after(message, "column ");
+
+        SourceLocation {
+            file_path: file_path.to_string(),
+            line,
+            column: line.and(column),
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{}:{}:{}", self.file_path, line, column),
+            (Some(line), None) => write!(f, "{}:{}", self.file_path, line),
+            _ => write!(f, "{}", self.file_path),
+        }
+    }
+}
+
+/// Finds the line and column of categories and attributes within their JSON files.
+///
+/// Files are read once and cached. If a file cannot be read, or the searched member cannot be found,
+/// the location only contains the file's path.
+pub struct SourceLocator {
+    contents: HashMap<String, Option<String>>,
+}
+
+impl SourceLocator {
+    pub fn new() -> SourceLocator {
+        SourceLocator {
+            contents: HashMap::new(),
+        }
+    }
+
+    /// Locates the category's definition, by its id or, if it has not been written yet, by its name.
+    pub fn locate_category(
+        &mut self,
+        file_path: &str,
+        category_id: Option<&str>,
+        category_name: Option<&str>,
+    ) -> SourceLocation {
+        let content = match self.content(file_path) {
+            Some(content) => content,
+            None => return SourceLocation::new(file_path),
+        };
+
+        let attributes_start = find_key(content, "attributes", 0).unwrap_or(content.len());
+        let position = category_id
+            .and_then(|id| find_member(&content[..attributes_start], "id", id, 0))
+            .or_else(|| {
+                category_name
+                    .and_then(|name| find_member(&content[..attributes_start], "name", name, 0))
+            });
+
+        to_location(file_path, content, position)
+    }
+
+    /// Locates the attribute's definition, by its id or, if it has not been written yet, by its name.
+    pub fn locate_attribute(
+        &mut self,
+        file_path: &str,
+        attribute_id: Option<&str>,
+        attribute_name: Option<&str>,
+    ) -> SourceLocation {
+        let content = match self.content(file_path) {
+            Some(content) => content,
+            None => return SourceLocation::new(file_path),
+        };
+
+        let attributes_start = find_key(content, "attributes", 0).unwrap_or(0);
+        let position = attribute_id
+            .and_then(|id| find_member(content, "id", id, attributes_start))
+            .or_else(|| {
+                attribute_name.and_then(|name| find_member(content, "name", name, attributes_start))
+            });
+
+        to_location(file_path, content, position)
+    }
+
+    fn content(&mut self, file_path: &str) -> Option<&str> {
+        self.contents
+            .entry(file_path.to_string())
+            .or_insert_with(|| std::fs::read_to_string(file_path).ok())
+            .as_deref()
+    }
+}
+
+impl Default for SourceLocator {
+    fn default() -> Self {
+        SourceLocator::new()
+    }
+}
+
+fn to_location(file_path: &str, content: &str, position: Option<usize>) -> SourceLocation {
+    match position {
+        Some(position) => {
+            let (line, column) = line_and_column(content, position);
+
+            SourceLocation::with_position(file_path, line, column)
+        }
+        None => SourceLocation::new(file_path),
+    }
+}
+
+/// Byte offset of the first `"key"` found at or after `from`.
+fn find_key(content: &str, key: &str, from: usize) -> Option<usize> {
+    let quoted_key = format!("\"{}\"", key);
+
+    content[from..]
+        .find(quoted_key.as_str())
+        .map(|offset| from + offset)
+}
+
+/// Byte offset of the first `"key": "value"` member found at or after `from`.
+fn find_member(content: &str, key: &str, value: &str, from: usize) -> Option<usize> {
+    let quoted_key = format!("\"{}\"", key);
+    let quoted_value = format!("\"{}\"", value);
+    let mut start = from;
+
+    while let Some(position) = find_key(content, key, start) {
+        let rest = content[position + quoted_key.len()..].trim_start();
+
+        if let Some(after_colon) = rest.strip_prefix(':') {
+            if after_colon.trim_start().starts_with(quoted_value.as_str()) {
+                return Some(position);
+            }
+        }
+
+        start = position + quoted_key.len();
+    }
+
+    None
+}
+
+/// One-based line and column of the byte offset.
+fn line_and_column(content: &str, position: usize) -> (usize, usize) {
+    let before = &content[..position];
+    let line = before.matches('\n').count() + 1;
+    let column = match before.rfind('\n') {
+        Some(line_start) => before[line_start + 1..].chars().count() + 1,
+        None => before.chars().count() + 1,
+    };
+
+    (line, column)
+}
+
+fn number_after(message: &str, prefix: &str) -> Option<usize> {
+    match message.rfind(prefix) {
+        Some(position) => {
+            let digits: String = message[position + prefix.len()..]
+                .chars()
+                .take_while(|character| character.is_ascii_digit())
+                .collect();
+
+            digits.parse().ok()
+        }
+        None => None,
+    }
+}
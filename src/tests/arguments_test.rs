@@ -0,0 +1,115 @@
+#[cfg(test)]
+use crate::arguments::{Arguments, Command};
+use crate::error::ErrorKind;
+
+fn to_args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn defaults_to_validate_with_default_paths() {
+    let arguments = Arguments::parse(&[]).unwrap();
+
+    assert_eq!(Command::Validate, arguments.command());
+    assert_eq!("./config.json", arguments.paths().config());
+    assert_eq!("./categories/", arguments.paths().categories_root());
+    assert_eq!(
+        "./category_id_tracker.csv",
+        arguments.paths().category_id_tracker()
+    );
+    assert_eq!(
+        "./attribute_id_tracker.csv",
+        arguments.paths().attribute_id_tracker()
+    );
+}
+
+#[test]
+fn parses_command_and_path_overrides() {
+    let arguments = Arguments::parse(
+        to_args(&[
+            "check",
+            "--config",
+            "/defs/config.json",
+            "--categories=/defs/categories",
+            "--category-tracker",
+            "/defs/categories.csv",
+            "--attribute-tracker=/defs/attributes.csv",
+        ])
+        .as_slice(),
+    )
+    .unwrap();
+
+    assert_eq!(Command::Check, arguments.command());
+    assert_eq!("/defs/config.json", arguments.paths().config());
+    assert_eq!("/defs/categories/", arguments.paths().categories_root());
+    assert_eq!(
+        "/defs/categories.csv",
+        arguments.paths().category_id_tracker()
+    );
+    assert_eq!(
+        "/defs/attributes.csv",
+        arguments.paths().attribute_id_tracker()
+    );
+}
+
+#[test]
+fn error_on_unknown_option() {
+    assert_eq!(
+        ErrorKind::InvalidArgument,
+        Arguments::parse(to_args(&["validate", "--unknown", "value"]).as_slice())
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn error_on_option_without_value() {
+    assert_eq!(
+        ErrorKind::InvalidArgument,
+        Arguments::parse(to_args(&["--config"]).as_slice())
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn error_on_multiple_commands() {
+    assert_eq!(
+        ErrorKind::InvalidArgument,
+        Arguments::parse(to_args(&["validate", "generate-ids"]).as_slice())
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn parses_deny_warnings_flag() {
+    let arguments =
+        Arguments::parse(to_args(&["check", "--deny-warnings", "--format", "json"]).as_slice())
+            .unwrap();
+
+    assert!(arguments.deny_warnings());
+    assert!(!Arguments::parse(&[]).unwrap().deny_warnings());
+}
+
+#[test]
+fn error_on_fix_layout_while_checking() {
+    assert!(Arguments::parse(to_args(&["--fix-layout"]).as_slice())
+        .unwrap()
+        .fix_layout());
+
+    let error = Arguments::parse(to_args(&["check", "--fix-layout"]).as_slice()).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidArgument, error.kind());
+}
+
+#[test]
+fn parses_the_sarif_base_directory() {
+    assert_eq!(".", Arguments::parse(&[]).unwrap().sarif_base());
+    assert_eq!(
+        "../defs",
+        Arguments::parse(to_args(&["--format", "sarif", "--sarif-base", "../defs"]).as_slice())
+            .unwrap()
+            .sarif_base()
+    );
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+use crate::attributes::attribute_tracker_file_io::AttributeTrackerFileIO;
+use crate::attributes::attribute_tracker_io::{AttributeEntry, AttributeTrackerIO};
+use crate::attributes::constraints::Constraints;
+use crate::tests::test_files::create_test_directory;
+
+#[test]
+fn reads_entries_that_predate_the_tracking_of_fields() {
+    let path = create_test_directory("reads_entries_that_predate_the_tracking_of_fields")
+        .join("attribute_id_tracker.csv");
+    std::fs::write(
+        &path,
+        "a1;date\na2;string;;code;c1;false;{\"pattern\":\"^[a-z;]+$\"}\na3;decimal;kg;weight;c1;;",
+    )
+    .unwrap();
+
+    let entries = AttributeTrackerFileIO::new(path.to_str().unwrap())
+        .read_entries()
+        .unwrap();
+
+    let legacy_entry = entries.get("a1").unwrap();
+    assert_eq!("date", legacy_entry.data_type);
+    assert_eq!(None, legacy_entry.unit);
+    assert_eq!(None, legacy_entry.name);
+    assert_eq!(None, legacy_entry.category_id);
+    assert_eq!(None, legacy_entry.constraints);
+    assert_eq!(None, legacy_entry.optional);
+
+    let complete_entry = entries.get("a2").unwrap();
+    assert_eq!(Some("".to_string()), complete_entry.unit);
+    assert_eq!(Some("code".to_string()), complete_entry.name);
+    assert_eq!(Some("c1".to_string()), complete_entry.category_id);
+    assert_eq!(Some(false), complete_entry.optional);
+    assert_eq!(
+        Some("^[a-z;]+$".to_string()),
+        complete_entry.constraints.clone().unwrap().pattern
+    );
+
+    let unconstrained_entry = entries.get("a3").unwrap();
+    assert_eq!(Some("kg".to_string()), unconstrained_entry.unit);
+    assert_eq!(None, unconstrained_entry.optional);
+    assert_eq!(None, unconstrained_entry.constraints);
+}
+
+#[test]
+fn error_if_line_has_neither_two_nor_every_field() {
+    let directory = create_test_directory("error_if_line_has_neither_two_nor_every_field");
+
+    for (index, line) in [
+        "a1",
+        "a1;decimal;kg",
+        "a1;decimal;kg;weight;c1",
+        "a1;decimal;kg;weight;c1;{}",
+    ]
+    .iter()
+    .enumerate()
+    {
+        let path = directory.join(format!("attribute_id_tracker_{}.csv", index));
+        std::fs::write(&path, line).unwrap();
+
+        let error = AttributeTrackerFileIO::new(path.to_str().unwrap())
+            .read_entries()
+            .err()
+            .unwrap();
+
+        assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    }
+}
+
+#[test]
+fn write_entries_rewrites_every_line_with_all_fields() {
+    let path = create_test_directory("write_entries_rewrites_every_line_with_all_fields")
+        .join("attribute_id_tracker.csv");
+    std::fs::write(&path, "b1;date\na1;decimal").unwrap();
+
+    let tracker_io = AttributeTrackerFileIO::new(path.to_str().unwrap());
+    let mut entries = tracker_io.read_entries().unwrap();
+    entries.get_mut("b1").unwrap().name = Some("expiry".to_string());
+
+    tracker_io.write_entries(&entries).unwrap();
+
+    assert_eq!(
+        "b1;date;;expiry;;;\na1;decimal;;;;;",
+        std::fs::read_to_string(&path).unwrap()
+    );
+}
+
+#[test]
+fn write_entries_appends_the_entries_that_were_not_within_the_file() {
+    let path =
+        create_test_directory("write_entries_appends_the_entries_that_were_not_within_the_file")
+            .join("attribute_id_tracker.csv");
+    std::fs::write(&path, "c1;date\na1;decimal").unwrap();
+
+    let tracker_io = AttributeTrackerFileIO::new(path.to_str().unwrap());
+    let mut entries = tracker_io.read_entries().unwrap();
+
+    for id in ["d1", "b1"] {
+        entries.insert(
+            id.to_string(),
+            AttributeEntry {
+                id: id.to_string(),
+                data_type: "string".to_string(),
+                unit: None,
+                name: None,
+                category_id: None,
+                constraints: None,
+                optional: None,
+            },
+        );
+    }
+
+    tracker_io.write_entries(&entries).unwrap();
+
+    assert_eq!(
+        "c1;date;;;;;\na1;decimal;;;;;\nb1;string;;;;;\nd1;string;;;;;",
+        std::fs::read_to_string(&path).unwrap()
+    );
+}
+
+#[test]
+fn escapes_the_separator_within_fields() {
+    let path = create_test_directory("escapes_the_separator_within_fields")
+        .join("attribute_id_tracker.csv");
+    std::fs::write(&path, "").unwrap();
+
+    let tracker_io = AttributeTrackerFileIO::new(path.to_str().unwrap());
+    tracker_io
+        .write_entry(&AttributeEntry {
+            id: "a1".to_string(),
+            data_type: "string".to_string(),
+            unit: Some("kg\\m".to_string()),
+            name: Some("salt;pepper".to_string()),
+            category_id: Some("c1".to_string()),
+            constraints: Some(Constraints::default()),
+            optional: Some(true),
+        })
+        .unwrap();
+
+    assert_eq!(
+        "\na1;string;kg\\\\m;salt\\;pepper;c1;true;{}",
+        std::fs::read_to_string(&path).unwrap()
+    );
+
+    let entry = tracker_io.read_entries().unwrap().remove("a1").unwrap();
+    assert_eq!(Some("kg\\m".to_string()), entry.unit);
+    assert_eq!(Some("salt;pepper".to_string()), entry.name);
+    assert_eq!(Some("c1".to_string()), entry.category_id);
+    assert_eq!(Some(Constraints::default()), entry.constraints);
+    assert_eq!(Some(true), entry.optional);
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+use cooplan_definitions_lib::attribute::Attribute;
+use serde_json::json;
+
+use crate::attributes::attribute_tracker_io::{AllowedAttributeChange, TrackedField};
+use crate::tests::test_attributes::{attribute, attribute_with_unit, constraints};
+
+#[test]
+fn allowed_changes_match_only_the_expected_value() {
+    let weight = attribute_with_unit("a1", "decimal", None);
+    let no_constraints = constraints(json!({}));
+
+    let allowed_change = |field: TrackedField, to: serde_json::Value| {
+        AllowedAttributeChange::new(field, to).matches("c1", &weight, &no_constraints)
+    };
+
+    assert!(allowed_change(TrackedField::Name, json!("a1")));
+    assert!(!allowed_change(TrackedField::Name, json!("a2")));
+    assert!(allowed_change(TrackedField::DataType, json!("decimal")));
+    assert!(!allowed_change(TrackedField::DataType, json!("integer")));
+    assert!(allowed_change(TrackedField::Unit, json!(null)));
+    assert!(allowed_change(TrackedField::Unit, json!("")));
+    assert!(!allowed_change(TrackedField::Unit, json!("kg")));
+    assert!(allowed_change(TrackedField::Category, json!("c1")));
+    assert!(!allowed_change(TrackedField::Category, json!("c2")));
+    assert!(allowed_change(TrackedField::Constraints, json!({})));
+    assert!(!allowed_change(
+        TrackedField::Constraints,
+        json!({ "min": 0 })
+    ));
+    assert!(!allowed_change(
+        TrackedField::Constraints,
+        json!({ "optional": true })
+    ));
+}
+
+#[test]
+fn allowed_constraint_changes_include_whether_the_attribute_is_optional() {
+    let size = Attribute {
+        optional: true,
+        ..attribute("a1", "integer")
+    };
+
+    let change = AllowedAttributeChange::new(
+        TrackedField::Constraints,
+        json!({ "optional": true, "max": 10 }),
+    );
+
+    assert!(change.matches("c1", &size, &constraints(json!({ "max": 10 }))));
+    assert!(!change.matches("c1", &size, &constraints(json!({ "max": 5 }))));
+}
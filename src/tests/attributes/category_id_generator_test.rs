@@ -49,6 +49,11 @@ fn unique_random_id_constraint() {
         let entry: AttributeEntry = AttributeEntry {
             id: attribute_id.clone(),
             data_type: "float".to_string(),
+            unit: None,
+            name: None,
+            category_id: None,
+            constraints: None,
+            optional: None,
         };
 
         entries.insert(attribute_id.clone(), entry);
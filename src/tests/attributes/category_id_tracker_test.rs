@@ -41,6 +41,11 @@ fn error_duplicated_id_attribute() {
         AttributeEntry {
             id: "ABCD".to_string(),
             data_type: "".to_string(),
+            unit: None,
+            name: None,
+            category_id: None,
+            constraints: None,
+            optional: None,
         },
     );
 
@@ -84,6 +89,11 @@ fn error_missing_ids() {
         AttributeEntry {
             id: "ABCD".to_string(),
             data_type: "".to_string(),
+            unit: None,
+            name: None,
+            category_id: None,
+            constraints: None,
+            optional: None,
         },
     );
 
@@ -107,6 +117,11 @@ fn id_comparison_is_correct() {
         AttributeEntry {
             id: "1234".to_string(),
             data_type: "".to_string(),
+            unit: None,
+            name: None,
+            category_id: None,
+            constraints: None,
+            optional: None,
         },
     );
     entries.insert(
@@ -114,6 +129,11 @@ fn id_comparison_is_correct() {
         AttributeEntry {
             id: "5678".to_string(),
             data_type: "".to_string(),
+            unit: None,
+            name: None,
+            category_id: None,
+            constraints: None,
+            optional: None,
         },
     );
 
@@ -158,6 +178,11 @@ fn track_and_close_successfully() {
         AttributeEntry {
             id: "ABCD".to_string(),
             data_type: "".to_string(),
+            unit: None,
+            name: None,
+            category_id: None,
+            constraints: None,
+            optional: None,
         },
     );
 
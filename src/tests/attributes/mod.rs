@@ -1,3 +1,6 @@
+pub mod attribute_tracker_file_io_test;
+pub mod attribute_tracker_io_test;
 pub mod category_id_generator_test;
 pub mod category_id_tracker_test;
+pub mod option_tracker_file_io_test;
 pub mod validations;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+use crate::attributes::option_tracker_file_io::OptionTrackerFileIO;
+use crate::attributes::option_tracker_io::{OptionEntry, OptionTrackerIO};
+use crate::tests::test_files::create_test_directory;
+
+#[test]
+fn escapes_line_breaks_and_the_separator_within_names() {
+    let path = create_test_directory("escapes_line_breaks_and_the_separator_within_names")
+        .join("option_id_tracker.csv");
+
+    let tracker_io = OptionTrackerFileIO::new(path.to_str().unwrap());
+    tracker_io
+        .write_entry(&OptionEntry {
+            id: "o1".to_string(),
+            attribute_id: "a1".to_string(),
+            name: "dark\nred;\\blue".to_string(),
+        })
+        .unwrap();
+    tracker_io
+        .write_entry(&OptionEntry {
+            id: "o2".to_string(),
+            attribute_id: "a1".to_string(),
+            name: "green".to_string(),
+        })
+        .unwrap();
+
+    assert_eq!(
+        "o1;a1;dark\\nred\\;\\\\blue\no2;a1;green",
+        std::fs::read_to_string(&path).unwrap()
+    );
+
+    let entries = tracker_io.read_entries().unwrap();
+    assert_eq!(2, entries.len());
+    assert_eq!("dark\nred;\\blue", entries.get("o1").unwrap().name);
+    assert_eq!("a1", entries.get("o1").unwrap().attribute_id);
+    assert_eq!("green", entries.get("o2").unwrap().name);
+}
+
+#[test]
+fn error_if_line_has_an_unescaped_separator() {
+    let path = create_test_directory("error_if_line_has_an_unescaped_separator")
+        .join("option_id_tracker.csv");
+    std::fs::write(&path, "o1;a1;red\no2;a1;dark;blue").unwrap();
+
+    let error = OptionTrackerFileIO::new(path.to_str().unwrap())
+        .read_entries()
+        .err()
+        .unwrap();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::validations::{
+    attribute_category_constant_validation::AttributeCategoryConstantValidation,
+    validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute, entries, entry};
+
+#[test]
+fn error_if_attribute_has_been_moved_to_another_category() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        entry("a1", "decimal"),
+        AttributeEntry {
+            category_id: Some("c2".to_string()),
+            ..entry("a2", "decimal")
+        },
+    ]);
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "decimal"), attribute("a2", "decimal")],
+    );
+
+    let mut validation = AttributeCategoryConstantValidation::new(&entries);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(
+        ErrorKind::AttributeCategoryChanged,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert_eq!(
+        Some(ErrorDetails::CategoryChange {
+            from: "c2".to_string(),
+            to: "c1".to_string()
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
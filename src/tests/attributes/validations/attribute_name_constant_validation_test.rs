@@ -0,0 +1,61 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::validations::{
+    attribute_name_constant_validation::AttributeNameConstantValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute_with_name, entries, entry};
+
+#[test]
+fn error_if_attribute_has_been_renamed() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        AttributeEntry {
+            name: Some("weight".to_string()),
+            ..entry("a1", "decimal")
+        },
+        AttributeEntry {
+            name: Some("height".to_string()),
+            ..entry("a2", "decimal")
+        },
+        AttributeEntry {
+            name: None,
+            ..entry("a3", "decimal")
+        },
+    ]);
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute_with_name("a1", "mass", "decimal"),
+            attribute_with_name("a2", "height", "decimal"),
+            attribute_with_name("a3", "width", "decimal"),
+        ],
+    );
+
+    let mut validation = AttributeNameConstantValidation::new(&entries);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(
+        ErrorKind::AttributeRenamed,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert_eq!(
+        Some(ErrorDetails::NameChange {
+            from: "weight".to_string(),
+            to: "mass".to_string()
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
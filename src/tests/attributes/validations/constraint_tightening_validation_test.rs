@@ -0,0 +1,169 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::{attribute::Attribute, category::Category};
+use serde_json::json;
+
+use crate::attributes::attribute_extension::AttributeExtension;
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::validations::{
+    constraint_tightening_validation::ConstraintTighteningValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute, constraints, entries, entry, extension};
+
+#[test]
+fn accepts_loosened_constraints_and_untracked_constraints() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        AttributeEntry {
+            constraints: Some(constraints(json!({ "min": 0, "max": 10 }))),
+            ..entry("a1", "integer")
+        },
+        AttributeEntry {
+            constraints: Some(constraints(json!({ "max": 10 }))),
+            ..entry("a2", "integer")
+        },
+        AttributeEntry {
+            constraints: None,
+            optional: Some(true),
+            ..entry("a3", "integer")
+        },
+    ]);
+    let extensions: HashMap<String, AttributeExtension> = vec![
+        extension("a1", json!({ "min": -5, "max": 20 })),
+        extension("a3", json!({ "max": 1 })),
+    ]
+    .into_iter()
+    .collect();
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            Attribute {
+                optional: true,
+                ..attribute("a1", "integer")
+            },
+            attribute("a2", "integer"),
+            attribute("a3", "integer"),
+        ],
+    );
+
+    let mut validation = ConstraintTighteningValidation::new(&entries, extensions);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert!(diagnostics.as_slice().is_empty());
+}
+
+#[test]
+fn error_if_constraints_are_tightened() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        AttributeEntry {
+            constraints: Some(constraints(json!({ "min": 0, "max": 10 }))),
+            ..entry("a1", "integer")
+        },
+        AttributeEntry {
+            optional: Some(true),
+            ..entry("a2", "integer")
+        },
+    ]);
+    let extensions: HashMap<String, AttributeExtension> =
+        vec![extension("a1", json!({ "min": 1, "max": 10 }))]
+            .into_iter()
+            .collect();
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "integer"), attribute("a2", "integer")],
+    );
+
+    let mut validation = ConstraintTighteningValidation::new(&entries, extensions);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    let tightenings: Vec<(ErrorKind, Option<String>, Option<ErrorDetails>)> = diagnostics
+        .as_slice()
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind(),
+                diagnostic.attribute_id.clone(),
+                diagnostic.details.clone(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            (
+                ErrorKind::ConstraintTightened,
+                Some("a1".to_string()),
+                Some(ErrorDetails::Constraint {
+                    constraint: "min".to_string()
+                })
+            ),
+            (
+                ErrorKind::ConstraintTightened,
+                Some("a2".to_string()),
+                Some(ErrorDetails::Constraint {
+                    constraint: "optional".to_string()
+                })
+            ),
+        ],
+        tightenings
+    );
+}
+
+#[test]
+fn reports_changed_patterns_and_accepts_removed_patterns() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        AttributeEntry {
+            constraints: Some(constraints(json!({ "pattern": "^[a-z]+$" }))),
+            ..entry("a1", "integer")
+        },
+        AttributeEntry {
+            constraints: Some(constraints(json!({ "pattern": "^[a-z]+$" }))),
+            ..entry("a2", "integer")
+        },
+    ]);
+    let extensions: HashMap<String, AttributeExtension> = vec![
+        extension("a1", json!({})),
+        extension("a2", json!({ "pattern": "^[a-z0-9]+$" })),
+    ]
+    .into_iter()
+    .collect();
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "integer"), attribute("a2", "integer")],
+    );
+
+    let mut validation = ConstraintTighteningValidation::new(&entries, extensions);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(
+        Some("a2".to_string()),
+        diagnostics.as_slice()[0].attribute_id
+    );
+    assert!(diagnostics.as_slice()[0].message.contains(
+        "'pattern' has been changed from '^[a-z]+$' to '^[a-z0-9]+$', which cannot be verified"
+    ));
+}
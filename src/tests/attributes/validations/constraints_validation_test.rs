@@ -0,0 +1,125 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::{attribute::Attribute, category::Category};
+use serde_json::json;
+
+use crate::attributes::attribute_extension::AttributeExtension;
+use crate::attributes::validations::{
+    constraints_validation::ConstraintsValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute, extension};
+
+fn validate(
+    attributes: Vec<Attribute>,
+    extensions: Vec<(String, AttributeExtension)>,
+) -> Diagnostics {
+    let category = Category::new("c1".to_string(), "first".to_string(), true, attributes);
+
+    let mut validation =
+        ConstraintsValidation::new(extensions.into_iter().collect::<HashMap<_, _>>());
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    diagnostics
+}
+
+#[test]
+fn accepts_constraints_legal_for_the_data_type() {
+    let diagnostics = validate(
+        vec![
+            attribute("a1", "integer"),
+            attribute("a2", "string(max=64)"),
+            Attribute {
+                optional: true,
+                ..attribute("a3", "list<string>")
+            },
+            attribute("a4", "date"),
+        ],
+        vec![
+            extension("a1", json!({ "min": 0, "max": 100 })),
+            extension(
+                "a2",
+                json!({ "min_length": 1, "max_length": 64, "pattern": "^[A-Z]" }),
+            ),
+            extension("a3", json!({ "max_length": 3 })),
+            extension("a4", json!({})),
+        ],
+    );
+
+    assert!(diagnostics.as_slice().is_empty());
+}
+
+#[test]
+fn reports_constraints_illegal_for_the_data_type() {
+    let diagnostics = validate(
+        vec![attribute("a1", "decimal"), attribute("a2", "boolean")],
+        vec![
+            extension("a1", json!({ "pattern": "^[0-9]" })),
+            extension("a2", json!({ "min": 0, "max_length": 1 })),
+        ],
+    );
+
+    let constraints: Vec<(ErrorKind, Option<String>)> = diagnostics
+        .as_slice()
+        .iter()
+        .map(|diagnostic| (diagnostic.kind(), diagnostic.attribute_id.clone()))
+        .collect();
+
+    assert_eq!(
+        vec![
+            (ErrorKind::InvalidConstraint, Some("a1".to_string())),
+            (ErrorKind::InvalidConstraint, Some("a2".to_string())),
+            (ErrorKind::InvalidConstraint, Some("a2".to_string())),
+        ],
+        constraints
+    );
+    assert_eq!(
+        Some(ErrorDetails::Constraint {
+            constraint: "pattern".to_string()
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
+
+#[test]
+fn reports_inconsistent_constraints() {
+    let diagnostics = validate(
+        vec![
+            Attribute {
+                optional: true,
+                ..attribute("a1", "integer")
+            },
+            attribute("a2", "string(max=10)"),
+        ],
+        vec![
+            extension("a1", json!({ "min": 5.5, "max": 1 })),
+            extension(
+                "a2",
+                json!({ "min_length": 8, "max_length": 12, "pattern": "[" }),
+            ),
+        ],
+    );
+
+    let constraints: Vec<Option<ErrorDetails>> = diagnostics
+        .as_slice()
+        .iter()
+        .map(|diagnostic| diagnostic.details.clone())
+        .collect();
+
+    let expected: Vec<Option<ErrorDetails>> = ["min", "min", "max_length", "pattern"]
+        .iter()
+        .map(|constraint| {
+            Some(ErrorDetails::Constraint {
+                constraint: constraint.to_string(),
+            })
+        })
+        .collect();
+
+    assert_eq!(expected, constraints);
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::validations::{
+    data_type_constant_validation::DataTypeConstantValidation, validation::Validation,
+};
+use crate::data_type_widenings::DataTypeWidenings;
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute, entries, entry};
+
+#[test]
+fn accepts_widenings_and_rejects_narrowings() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        entry("a1", "integer"),
+        entry("a2", "decimal"),
+        entry("a3", "boolean"),
+    ]);
+
+    let widenings = DataTypeWidenings::new(
+        vec![("integer".to_string(), vec!["decimal".to_string()])]
+            .into_iter()
+            .collect(),
+    );
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute("a1", "decimal"),
+            attribute("a2", "integer"),
+            attribute("a3", "string"),
+        ],
+    );
+
+    let mut validation = DataTypeConstantValidation::new(&entries, widenings);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(
+        ErrorKind::FailedDataTypeConstantAttributeValidation,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert!(diagnostics.as_slice()[0].message.contains("narrowed"));
+    assert_eq!(
+        Some(ErrorDetails::DataTypeChange {
+            from: "decimal".to_string(),
+            to: "integer".to_string()
+        }),
+        diagnostics.as_slice()[0].details
+    );
+    assert!(diagnostics.as_slice()[1].message.contains("changed"));
+}
+
+#[test]
+fn rejects_widenings_that_reduce_the_bounds_of_the_data_type() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        entry("a1", "integer"),
+        entry("a2", "integer"),
+        entry("a3", "list<string>"),
+    ]);
+
+    let widenings = DataTypeWidenings::new(
+        vec![
+            ("integer".to_string(), vec!["decimal".to_string()]),
+            (
+                "list<integer>".to_string(),
+                vec!["list<decimal>".to_string()],
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    );
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute("a1", "decimal"),
+            attribute("a2", "decimal(precision=3,scale=2)"),
+            attribute("a3", "list<boolean>"),
+        ],
+    );
+
+    let mut validation = DataTypeConstantValidation::new(&entries, widenings);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(2, diagnostics.len());
+    assert!(diagnostics.as_slice()[0].message.contains("'a2'"));
+    assert!(diagnostics.as_slice()[1].message.contains("'a3'"));
+}
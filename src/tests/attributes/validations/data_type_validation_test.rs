@@ -0,0 +1,78 @@
+#[cfg(test)]
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::validations::{
+    data_type_validation::DataTypeValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::ErrorKind;
+use crate::tests::test_attributes::attribute;
+
+#[test]
+fn reports_every_invalid_data_type_across_categories() {
+    let first = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "string"), attribute("a2", "float")],
+    );
+    let second = Category::new(
+        "c2".to_string(),
+        "second".to_string(),
+        true,
+        vec![attribute("a3", "date"), attribute("a4", "integer")],
+    );
+
+    let mut validation = DataTypeValidation::new(vec!["string".to_string(), "integer".to_string()]);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&first.borrow(), &mut diagnostics)
+        .unwrap();
+    validation
+        .partially_validate(&second.borrow(), &mut diagnostics)
+        .unwrap();
+    validation.complete(&mut diagnostics).unwrap();
+
+    assert_eq!(2, diagnostics.len());
+
+    let first_diagnostic = &diagnostics.as_slice()[0];
+    assert_eq!(
+        ErrorKind::FailedDataTypeAttributeValidation,
+        first_diagnostic.kind()
+    );
+    assert_eq!(Some("c1".to_string()), first_diagnostic.category_id);
+    assert_eq!(Some("a2".to_string()), first_diagnostic.attribute_id);
+
+    let second_diagnostic = &diagnostics.as_slice()[1];
+    assert_eq!(Some("second".to_string()), second_diagnostic.category_name);
+    assert_eq!(Some("a3".to_string()), second_diagnostic.attribute_name);
+}
+
+#[test]
+fn reports_invalid_parameters_of_data_types() {
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute("a1", "string(max=64)"),
+            attribute("a2", "string(max=64"),
+            attribute("a3", "string(scale=2)"),
+        ],
+    );
+
+    let mut validation = DataTypeValidation::new(vec!["string".to_string()]);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(
+        Some("a2".to_string()),
+        diagnostics.as_slice()[0].attribute_id
+    );
+    assert!(diagnostics.as_slice()[1].message.contains("scale"));
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::{attribute::Attribute, category::Category};
+use serde_json::json;
+
+use crate::attributes::attribute_extension::{AttributeExtension, AttributeOption};
+use crate::attributes::validations::{
+    default_value_validation::DefaultValueValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute_with_unit, extension_with_default};
+
+fn validate(
+    attributes: Vec<Attribute>,
+    extensions: Vec<(String, AttributeExtension)>,
+) -> Vec<String> {
+    let category = Category::new("c1".to_string(), "first".to_string(), true, attributes);
+    let data_type_formats: HashMap<String, String> =
+        vec![("date".to_string(), r"^\d{4}-\d{2}-\d{2}$".to_string())]
+            .into_iter()
+            .collect();
+
+    let mut validation =
+        DefaultValueValidation::new(extensions.into_iter().collect(), data_type_formats);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    diagnostics
+        .as_slice()
+        .iter()
+        .map(|diagnostic| {
+            assert_eq!(ErrorKind::InvalidDefaultValue, diagnostic.kind());
+
+            diagnostic.attribute_id.clone().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn accepts_defaults_of_the_attributes_data_type() {
+    let mut size = extension_with_default("a6", json!("small"), json!({}));
+    size.1.options = vec![AttributeOption {
+        id: None,
+        name: "small".to_string(),
+    }];
+
+    let invalid_attribute_ids = validate(
+        vec![
+            attribute_with_unit("a1", "boolean", None),
+            attribute_with_unit("a2", "integer", Some("g")),
+            attribute_with_unit("a3", "decimal(precision=4,scale=2)", Some("kg")),
+            attribute_with_unit("a4", "string(max=8)", None),
+            attribute_with_unit("a5", "enum[red,green]", None),
+            attribute_with_unit("a6", "enum", None),
+            attribute_with_unit("a7", "list<integer>", None),
+            attribute_with_unit("a8", "date", None),
+            attribute_with_unit("a9", "color", None),
+        ],
+        vec![
+            extension_with_default("a1", json!(true), json!({})),
+            extension_with_default("a2", json!(5), json!({ "min": 0, "max": 10 })),
+            extension_with_default("a3", json!("12.5 kg"), json!({})),
+            extension_with_default("a4", json!("Apple"), json!({ "pattern": "^[A-Z]" })),
+            extension_with_default("a5", json!("red"), json!({})),
+            size,
+            extension_with_default("a7", json!([1, 2]), json!({ "max_length": 2 })),
+            extension_with_default("a8", json!("2024-01-31"), json!({})),
+            extension_with_default("a9", json!("#ff0000"), json!({})),
+        ],
+    );
+
+    assert!(invalid_attribute_ids.is_empty());
+}
+
+#[test]
+fn reports_defaults_that_do_not_parse_as_the_data_type() {
+    let invalid_attribute_ids = validate(
+        vec![
+            attribute_with_unit("a1", "boolean", None),
+            attribute_with_unit("a2", "integer", None),
+            attribute_with_unit("a3", "decimal(precision=4,scale=2)", None),
+            attribute_with_unit("a4", "string(max=3)", None),
+            attribute_with_unit("a5", "enum[red,green]", None),
+            attribute_with_unit("a6", "enum", None),
+            attribute_with_unit("a7", "list<integer>", None),
+            attribute_with_unit("a8", "date", None),
+            attribute_with_unit("a9", "not a data type", None),
+        ],
+        vec![
+            extension_with_default("a1", json!("yes"), json!({})),
+            extension_with_default("a2", json!(1.5), json!({})),
+            extension_with_default("a3", json!(1.234), json!({})),
+            extension_with_default("a4", json!("long"), json!({})),
+            extension_with_default("a5", json!("blue"), json!({})),
+            extension_with_default("a6", json!("small"), json!({})),
+            extension_with_default("a7", json!([1, "2"]), json!({})),
+            extension_with_default("a8", json!("31/01/2024"), json!({})),
+            extension_with_default("a9", json!(1), json!({})),
+        ],
+    );
+
+    assert_eq!(
+        vec!["a1", "a2", "a3", "a4", "a5", "a6", "a7", "a8"],
+        invalid_attribute_ids
+    );
+}
+
+#[test]
+fn reports_defaults_that_do_not_respect_the_constraints_or_unit() {
+    let extensions = vec![
+        extension_with_default("a1", json!(11), json!({ "max": 10 })),
+        extension_with_default("a2", json!("apple"), json!({ "pattern": "^[A-Z]" })),
+        extension_with_default("a3", json!([]), json!({ "min_length": 1 })),
+        extension_with_default("a4", json!("5 g"), json!({})),
+        extension_with_default("a5", json!("5 g"), json!({})),
+    ];
+
+    let invalid_attribute_ids = validate(
+        vec![
+            attribute_with_unit("a1", "integer", None),
+            attribute_with_unit("a2", "string", None),
+            attribute_with_unit("a3", "list<string>", None),
+            attribute_with_unit("a4", "decimal", Some("kg")),
+            attribute_with_unit("a5", "decimal", None),
+        ],
+        extensions,
+    );
+
+    assert_eq!(vec!["a1", "a2", "a3", "a4", "a5"], invalid_attribute_ids);
+}
+
+#[test]
+fn details_the_default_value_and_its_violations() {
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute_with_unit("a1", "integer", None)],
+    );
+
+    let mut validation = DefaultValueValidation::new(
+        vec![extension_with_default("a1", json!(-1), json!({ "min": 0 }))]
+            .into_iter()
+            .collect(),
+        HashMap::new(),
+    );
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(
+        Some(ErrorDetails::DefaultValue {
+            value: "-1".to_string(),
+            violations: vec!["it is lower than 'min' (0)".to_string()]
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::validations::{
+    deprecated_data_type_validation::DeprecatedDataTypeValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::attribute;
+
+#[test]
+fn reports_attributes_with_deprecated_data_type() {
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "float"), attribute("a2", "decimal")],
+    );
+
+    let mut deprecated_data_types = HashMap::new();
+    deprecated_data_types.insert("float".to_string(), "use 'decimal' instead".to_string());
+
+    let mut validation = DeprecatedDataTypeValidation::new(deprecated_data_types);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+    validation.complete(&mut diagnostics).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+
+    let diagnostic = &diagnostics.as_slice()[0];
+    assert_eq!(ErrorKind::DeprecatedDataType, diagnostic.kind());
+    assert_eq!(Some("a1".to_string()), diagnostic.attribute_id);
+    assert!(diagnostic.message.ends_with("use 'decimal' instead"));
+    assert_eq!(
+        Some(ErrorDetails::DataType {
+            data_type: "float".to_string()
+        }),
+        diagnostic.details
+    );
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::validations::{
+    duplicate_attribute_name_validation::DuplicateAttributeNameValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::attribute_with_name;
+
+#[test]
+fn error_if_attributes_of_a_category_share_their_name() {
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute_with_name("a1", "color", "string"),
+            attribute_with_name("a2", "color", "string"),
+            attribute_with_name("a3", "size", "string"),
+        ],
+    );
+
+    let mut validation = DuplicateAttributeNameValidation::new();
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(
+        ErrorKind::DuplicatedAttributeName,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert_eq!(
+        Some(ErrorDetails::Name {
+            name: "color".to_string(),
+            ids: vec!["a1".to_string(), "a2".to_string()]
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
+
+#[test]
+fn error_if_attribute_shadows_an_inherited_one() {
+    let root = Category::new(
+        "c1".to_string(),
+        "food".to_string(),
+        false,
+        vec![attribute_with_name("a1", "color", "string")],
+    );
+    let child = Category::new_into_parent(
+        "c2".to_string(),
+        Rc::downgrade(&root),
+        "fruit".to_string(),
+        false,
+        Vec::new(),
+    )
+    .unwrap();
+    let grandchild = Category::new_into_parent(
+        "c3".to_string(),
+        Rc::downgrade(&child),
+        "pear".to_string(),
+        true,
+        vec![attribute_with_name("a2", "color", "string")],
+    )
+    .unwrap();
+
+    let mut validation = DuplicateAttributeNameValidation::new();
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&grandchild.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(
+        ErrorKind::ShadowedAttributeName,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert_eq!(
+        Some(ErrorDetails::Name {
+            name: "color".to_string(),
+            ids: vec!["a2".to_string(), "a1".to_string()]
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
+
+#[test]
+fn no_errors_if_attribute_names_are_unique() {
+    let root = Category::new(
+        "c1".to_string(),
+        "food".to_string(),
+        false,
+        vec![attribute_with_name("a1", "color", "string")],
+    );
+    let child = Category::new_into_parent(
+        "c2".to_string(),
+        Rc::downgrade(&root),
+        "fruit".to_string(),
+        true,
+        vec![attribute_with_name("a2", "size", "string")],
+    )
+    .unwrap();
+
+    let mut validation = DuplicateAttributeNameValidation::new();
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&child.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert!(diagnostics.is_empty());
+}
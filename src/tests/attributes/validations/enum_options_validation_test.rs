@@ -0,0 +1,58 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_extension::AttributeExtension;
+use crate::attributes::validations::{
+    enum_options_validation::EnumOptionsValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::ErrorKind;
+use crate::tests::test_attributes::{attribute, extension_with_options};
+
+#[test]
+fn reports_missing_unexpected_and_duplicated_options() {
+    let extensions: HashMap<String, AttributeExtension> = vec![
+        extension_with_options("a1", &["red", "green"]),
+        extension_with_options("a3", &["red"]),
+        extension_with_options("a4", &["red", "red"]),
+    ]
+    .into_iter()
+    .collect();
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute("a1", "enum"),
+            attribute("a2", "enum"),
+            attribute("a3", "string"),
+            attribute("a4", "enum"),
+            attribute("a5", "enum[red,green]"),
+        ],
+    );
+
+    let mut validation = EnumOptionsValidation::new(extensions);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    let kinds: Vec<ErrorKind> = diagnostics
+        .as_slice()
+        .iter()
+        .map(|diagnostic| diagnostic.kind())
+        .collect();
+
+    assert_eq!(
+        vec![
+            ErrorKind::MissingOptions,
+            ErrorKind::UnexpectedOptions,
+            ErrorKind::DuplicatedOptionName
+        ],
+        kinds
+    );
+}
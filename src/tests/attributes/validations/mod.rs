@@ -1 +1,14 @@
+pub mod attribute_category_constant_validation_test;
+pub mod attribute_name_constant_validation_test;
+pub mod constraint_tightening_validation_test;
+pub mod constraints_validation_test;
+pub mod data_type_constant_validation_test;
+pub mod data_type_validation_test;
+pub mod default_value_validation_test;
+pub mod deprecated_data_type_validation_test;
+pub mod duplicate_attribute_name_validation_test;
+pub mod enum_options_validation_test;
+pub mod option_id_tracking_validation_test;
 pub mod reserved_attribute_names_validation;
+pub mod unit_constant_validation_test;
+pub mod unit_validation_test;
@@ -0,0 +1,123 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_extension::{AttributeExtension, AttributeOption};
+use crate::attributes::option_tracker_io::OptionEntry;
+use crate::attributes::validations::{
+    option_id_tracking_validation::OptionIdTrackingValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::ErrorKind;
+use crate::tests::test_attributes::{attribute, option_entry};
+
+fn option(id: &str, name: &str) -> AttributeOption {
+    AttributeOption {
+        id: Some(id.to_string()),
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn reports_removed_reused_and_untracked_options() {
+    let entries: HashMap<String, OptionEntry> = vec![
+        option_entry("o1", "a1", "red"),
+        option_entry("o2", "a1", "green"),
+        option_entry("o3", "a1", "blue"),
+        option_entry("o4", "a2", "small"),
+    ]
+    .into_iter()
+    .collect();
+
+    let extensions: HashMap<String, AttributeExtension> = vec![
+        (
+            "a1".to_string(),
+            AttributeExtension {
+                options: vec![option("o1", "red"), option("o2", "yellow")],
+                ..Default::default()
+            },
+        ),
+        (
+            "a2".to_string(),
+            AttributeExtension {
+                options: vec![
+                    option("o4", "small"),
+                    option("o3", "blue"),
+                    option("o5", "large"),
+                ],
+                ..Default::default()
+            },
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "enum"), attribute("a2", "enum")],
+    );
+
+    let mut validation = OptionIdTrackingValidation::new(&entries, extensions);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+    validation.complete(&mut diagnostics).unwrap();
+
+    let kinds: Vec<ErrorKind> = diagnostics
+        .as_slice()
+        .iter()
+        .map(|diagnostic| diagnostic.kind())
+        .collect();
+
+    assert_eq!(
+        vec![
+            ErrorKind::OptionReused,
+            ErrorKind::OptionReused,
+            ErrorKind::OptionIdNotTracked
+        ],
+        kinds
+    );
+}
+
+#[test]
+fn error_if_tracked_option_has_been_removed() {
+    let entries: HashMap<String, OptionEntry> = vec![
+        option_entry("o1", "a1", "red"),
+        option_entry("o2", "a1", "green"),
+    ]
+    .into_iter()
+    .collect();
+
+    let extensions: HashMap<String, AttributeExtension> = vec![(
+        "a1".to_string(),
+        AttributeExtension {
+            options: vec![option("o1", "red")],
+            ..Default::default()
+        },
+    )]
+    .into_iter()
+    .collect();
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute("a1", "enum")],
+    );
+
+    let mut validation = OptionIdTrackingValidation::new(&entries, extensions);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+    validation.complete(&mut diagnostics).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(ErrorKind::OptionRemoved, diagnostics.as_slice()[0].kind());
+}
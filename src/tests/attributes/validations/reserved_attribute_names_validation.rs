@@ -1,12 +1,13 @@
 #[cfg(test)]
 #[test]
 fn detects_reserved_keywords_within_attributes_names() {
-    use cooplan_definitions_lib::attribute::Attribute;
+    use cooplan_definitions_lib::{attribute::Attribute, category::Category};
 
     use crate::attributes::validations::{
         reserved_attribute_names_validation::ReservedAttributeNamesValidation,
         validation::Validation,
     };
+    use crate::diagnostics::Diagnostics;
 
     let mut attributes: Vec<Attribute> = Vec::new();
 
@@ -31,19 +32,24 @@ fn detects_reserved_keywords_within_attributes_names() {
     let reserved_keywords: Vec<String> = vec!["type".to_string(), "version".to_string()];
     let mut validation = ReservedAttributeNamesValidation::new(reserved_keywords);
 
-    assert!(validation
-        .partially_validate(attributes.as_slice())
-        .is_err());
+    let category = Category::new("id".to_string(), "name".to_string(), true, attributes);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+    assert_eq!(1, diagnostics.len());
 }
 
 #[test]
 fn no_false_positives() {
-    use cooplan_definitions_lib::attribute::Attribute;
+    use cooplan_definitions_lib::{attribute::Attribute, category::Category};
 
     use crate::attributes::validations::{
         reserved_attribute_names_validation::ReservedAttributeNamesValidation,
         validation::Validation,
     };
+    use crate::diagnostics::Diagnostics;
 
     let mut attributes: Vec<Attribute> = Vec::new();
 
@@ -68,5 +74,11 @@ fn no_false_positives() {
     let reserved_keywords: Vec<String> = vec!["type".to_string(), "version".to_string()];
     let mut validation = ReservedAttributeNamesValidation::new(reserved_keywords);
 
-    assert!(validation.partially_validate(attributes.as_slice()).is_ok());
+    let category = Category::new("id".to_string(), "name".to_string(), true, attributes);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+    assert!(diagnostics.is_empty());
 }
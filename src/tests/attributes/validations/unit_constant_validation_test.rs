@@ -0,0 +1,98 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::validations::{
+    unit_constant_validation::UnitConstantValidation, validation::Validation,
+};
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::tests::test_attributes::{attribute_with_unit, entries, entry_with_unit};
+
+#[test]
+fn error_if_unit_changed() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        entry_with_unit("a1", "decimal", Some("kg")),
+        entry_with_unit("a2", "decimal", Some("")),
+        entry_with_unit("a3", "decimal", Some("m")),
+    ]);
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute_with_unit("a1", "decimal", Some("g")),
+            attribute_with_unit("a2", "decimal", Some("m")),
+            attribute_with_unit("a3", "decimal", Some("m")),
+        ],
+    );
+
+    let mut validation = UnitConstantValidation::new(&entries);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(ErrorKind::UnitChanged, diagnostics.as_slice()[0].kind());
+    assert_eq!(
+        Some(ErrorDetails::UnitChange {
+            from: "kg".to_string(),
+            to: "g".to_string()
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
+
+#[test]
+fn entries_without_tracked_unit_are_not_validated() {
+    let entries: HashMap<String, AttributeEntry> =
+        entries(vec![entry_with_unit("a1", "decimal", None)]);
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![attribute_with_unit("a1", "decimal", Some("g"))],
+    );
+
+    let mut validation = UnitConstantValidation::new(&entries);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn empty_units_are_the_same_as_no_unit() {
+    let entries: HashMap<String, AttributeEntry> = entries(vec![
+        entry_with_unit("a1", "decimal", Some("")),
+        entry_with_unit("a2", "decimal", Some("")),
+    ]);
+
+    let category = Category::new(
+        "c1".to_string(),
+        "first".to_string(),
+        true,
+        vec![
+            attribute_with_unit("a1", "decimal", Some("")),
+            attribute_with_unit("a2", "decimal", None),
+        ],
+    );
+
+    let mut validation = UnitConstantValidation::new(&entries);
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+
+    assert!(diagnostics.is_empty());
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::{attribute::Attribute, category::Category};
+
+use crate::attributes::validations::{unit_validation::UnitValidation, validation::Validation};
+use crate::diagnostics::Diagnostics;
+use crate::error::ErrorKind;
+use crate::tests::test_attributes::attribute_with_unit;
+use crate::unit_catalog::{DataTypeUnits, UnitCatalog};
+
+fn unit_catalog() -> UnitCatalog {
+    let mut data_types = HashMap::new();
+    data_types.insert(
+        "decimal".to_string(),
+        DataTypeUnits {
+            units: vec!["g".to_string(), "m".to_string()],
+            prefixes: vec!["k".to_string()],
+        },
+    );
+
+    data_types.insert(
+        "string".to_string(),
+        DataTypeUnits {
+            units: vec!["chars".to_string()],
+            prefixes: Vec::new(),
+        },
+    );
+
+    UnitCatalog::new(data_types)
+}
+
+fn validate(attributes: Vec<Attribute>) -> Diagnostics {
+    let category = Category::new("c1".to_string(), "first".to_string(), true, attributes);
+
+    let mut validation = UnitValidation::new(unit_catalog());
+    let mut diagnostics = Diagnostics::new();
+
+    validation
+        .partially_validate(&category.borrow(), &mut diagnostics)
+        .unwrap();
+    validation.complete(&mut diagnostics).unwrap();
+
+    diagnostics
+}
+
+#[test]
+fn accepts_units_and_prefixed_units_of_the_catalog() {
+    let diagnostics = validate(vec![
+        attribute_with_unit("a1", "decimal", Some("g")),
+        attribute_with_unit("a2", "decimal", Some("km")),
+        attribute_with_unit("a3", "string", None),
+        attribute_with_unit("a4", "string", Some("")),
+    ]);
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn error_if_data_type_does_not_accept_units() {
+    let diagnostics = validate(vec![attribute_with_unit("a1", "string", Some("g"))]);
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(ErrorKind::UnitNotAllowed, diagnostics.as_slice()[0].kind());
+}
+
+#[test]
+fn error_if_unit_is_not_within_the_catalog() {
+    let diagnostics = validate(vec![
+        attribute_with_unit("a1", "decimal", Some("lb")),
+        attribute_with_unit("a2", "decimal", Some("mg")),
+    ]);
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(ErrorKind::UnknownUnit, diagnostics.as_slice()[0].kind());
+}
+
+#[test]
+fn error_if_non_numeric_data_type_has_a_unit_within_the_catalog() {
+    let diagnostics = validate(vec![attribute_with_unit("a1", "string", Some("chars"))]);
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(ErrorKind::UnitNotAllowed, diagnostics.as_slice()[0].kind());
+}
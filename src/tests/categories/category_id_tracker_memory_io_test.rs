@@ -0,0 +1,30 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use crate::categories::category_id_tracker::CategoryEntry;
+use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
+use crate::categories::category_id_tracker_memory_io::CategoryIdTrackerMemoryIO;
+
+#[test]
+fn written_entries_are_read_back() {
+    let mut entries: HashMap<String, CategoryEntry> = HashMap::new();
+    entries.insert(
+        "id".to_string(),
+        CategoryEntry {
+            id: "id".to_string(),
+        },
+    );
+
+    let tracker_io = CategoryIdTrackerMemoryIO::new(entries);
+    tracker_io
+        .write_entry(&CategoryEntry {
+            id: "id2".to_string(),
+        })
+        .unwrap();
+
+    let read_entries = tracker_io.read_entries().unwrap();
+
+    assert_eq!(2, read_entries.len());
+    assert!(read_entries.contains_key("id"));
+    assert!(read_entries.contains_key("id2"));
+}
@@ -1,5 +1,3 @@
-use std::borrow::Borrow;
-use std::ops::Deref;
 use std::rc::Rc;
 
 use cooplan_definitions_lib::category::Category;
@@ -0,0 +1,50 @@
+#[cfg(test)]
+use crate::categories::category_tree_order::{sort_by_parent, ParentReference};
+
+fn category(id: &str, parent_id: Option<&str>) -> ParentReference {
+    ParentReference {
+        id: id.to_string(),
+        name: id.to_string(),
+        parent_id: parent_id.map(|parent_id| parent_id.to_string()),
+    }
+}
+
+#[test]
+fn sorts_parents_before_children() {
+    let order = sort_by_parent(&[
+        category("grandchild", Some("child")),
+        category("child", Some("root")),
+        category("root", None),
+    ]);
+
+    assert_eq!(vec![2, 1, 0], order.sorted);
+    assert!(order.missing_parents.is_empty());
+    assert!(order.cycles.is_empty());
+}
+
+#[test]
+fn reports_missing_parents_and_skips_their_descendants() {
+    let order = sort_by_parent(&[
+        category("child", Some("missing")),
+        category("grandchild", Some("child")),
+        category("root", None),
+    ]);
+
+    assert_eq!(vec![2], order.sorted);
+    assert_eq!(vec![0], order.missing_parents);
+    assert!(order.cycles.is_empty());
+}
+
+#[test]
+fn reports_every_category_of_each_cycle() {
+    let order = sort_by_parent(&[
+        category("a", Some("b")),
+        category("b", Some("c")),
+        category("c", Some("a")),
+        category("d", Some("a")),
+        category("e", Some("e")),
+    ]);
+
+    assert!(order.sorted.is_empty());
+    assert_eq!(vec![vec![0, 1, 2], vec![4]], order.cycles);
+}
@@ -1,3 +1,5 @@
 pub mod category_id_generator_test;
+pub mod category_id_tracker_memory_io_test;
 pub mod category_id_tracker_test;
+pub mod category_tree_order_test;
 pub mod validations;
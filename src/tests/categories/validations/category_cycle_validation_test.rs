@@ -0,0 +1,78 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use crate::categories::category_tree_order::{sort_by_parent, ParentReference};
+use crate::categories::validations::category_cycle_validation::CategoryCycleValidation;
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+
+fn category(id: &str, parent_id: Option<&str>) -> ParentReference {
+    ParentReference {
+        id: id.to_string(),
+        name: id.to_string(),
+        parent_id: parent_id.map(|parent_id| parent_id.to_string()),
+    }
+}
+
+fn validate(
+    categories_path: HashMap<String, String>,
+    categories: &[ParentReference],
+) -> Diagnostics {
+    let mut diagnostics = Diagnostics::new();
+
+    CategoryCycleValidation::new(categories_path).validate(
+        categories,
+        sort_by_parent(categories).cycles.as_slice(),
+        &mut diagnostics,
+    );
+
+    diagnostics
+}
+
+#[test]
+fn reports_every_category_of_a_cycle() {
+    let mut categories_path = HashMap::new();
+    categories_path.insert("a".to_string(), "b/a.json".to_string());
+
+    let diagnostics = validate(
+        categories_path,
+        &[
+            category("root", None),
+            category("a", Some("c")),
+            category("b", Some("a")),
+            category("c", Some("b")),
+            category("d", Some("c")),
+        ],
+    );
+
+    assert_eq!(3, diagnostics.len());
+
+    for diagnostic in diagnostics.as_slice() {
+        assert_eq!(ErrorKind::CategoryCycle, diagnostic.kind());
+        assert!(diagnostic.message.contains("'a' (a, b/a.json)"));
+        assert_eq!(
+            Some(ErrorDetails::Ids {
+                ids: vec!["a".to_string(), "c".to_string(), "b".to_string()]
+            }),
+            diagnostic.details
+        );
+    }
+}
+
+#[test]
+fn reports_categories_that_are_their_own_parent() {
+    let diagnostics = validate(HashMap::new(), &[category("a", Some("a"))]);
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(Some("a".to_string()), diagnostics.as_slice()[0].category_id);
+}
+
+#[test]
+fn no_errors_if_there_are_no_cycles() {
+    let diagnostics = validate(
+        HashMap::new(),
+        &[category("root", None), category("child", Some("root"))],
+    );
+
+    assert!(diagnostics.is_empty());
+}
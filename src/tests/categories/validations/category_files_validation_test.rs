@@ -0,0 +1,107 @@
+#[cfg(test)]
+use crate::categories::validations::category_files_validation::CategoryFilesValidation;
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
+use crate::error::ErrorKind;
+use crate::tests::test_files::create_test_directory;
+
+const CATEGORY_JSON: &str = r#"{"id": "food-id", "name": "food", "attributes": []}"#;
+
+fn validate_files(
+    test_name: &str,
+    files: &[(&str, &str)],
+    directories: &[&str],
+    allowed_files: &[&str],
+) -> Diagnostics {
+    let directory = create_test_directory(test_name);
+
+    for (file, content) in files {
+        let file_path = directory.join(file);
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        std::fs::write(file_path, content).unwrap();
+    }
+
+    for empty_directory in directories {
+        std::fs::create_dir_all(directory.join(empty_directory)).unwrap();
+    }
+
+    let validation = CategoryFilesValidation::new(
+        directory.to_str().unwrap(),
+        allowed_files
+            .iter()
+            .map(|allowed_file| allowed_file.to_string())
+            .collect(),
+    );
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[], &mut diagnostics).unwrap();
+
+    diagnostics
+}
+
+#[test]
+fn no_errors_if_there_are_only_category_definitions() {
+    let diagnostics = validate_files(
+        "no_errors_if_there_are_only_category_definitions",
+        &[
+            ("food.json", CATEGORY_JSON),
+            ("food/fruit.json", CATEGORY_JSON),
+        ],
+        &[],
+        &[],
+    );
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn error_if_there_are_stray_files() {
+    let diagnostics = validate_files(
+        "error_if_there_are_stray_files",
+        &[
+            ("food.json", CATEGORY_JSON),
+            ("food/abcd.txt", "abcd"),
+            ("food/fruit.json", "{\n  \"name\": }"),
+        ],
+        &[],
+        &[],
+    );
+
+    // Invalid definitions are reported while loading the categories.
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(ErrorKind::StrayFile, diagnostics.as_slice()[0].kind());
+}
+
+#[test]
+fn reports_outermost_empty_directory() {
+    let diagnostics = validate_files(
+        "reports_outermost_empty_directory",
+        &[("food.json", CATEGORY_JSON)],
+        &["food/fruit/pear"],
+        &[],
+    );
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(ErrorKind::EmptyDirectory, diagnostics.as_slice()[0].kind());
+    assert!(diagnostics.as_slice()[0]
+        .file_path
+        .as_ref()
+        .unwrap()
+        .ends_with("food"));
+}
+
+#[test]
+fn allowed_files_are_not_reported() {
+    let diagnostics = validate_files(
+        "allowed_files_are_not_reported",
+        &[
+            ("food.json", CATEGORY_JSON),
+            ("food/README.md", "# Food"),
+            ("schemas/category.json", "{}"),
+        ],
+        &[],
+        &["README.md", "schemas/**"],
+    );
+
+    assert!(diagnostics.is_empty());
+}
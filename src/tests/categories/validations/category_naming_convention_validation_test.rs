@@ -0,0 +1,53 @@
+#[cfg(test)]
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::category_naming_convention_validation::CategoryNamingConventionValidation;
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
+use crate::error::ErrorKind;
+use crate::naming_convention::{NamingConvention, NamingPreset};
+
+#[test]
+fn error_if_category_name_does_not_follow_the_convention() {
+    let root = Category::new("c1".to_string(), "food".to_string(), false, Vec::new());
+    Category::new_into_parent(
+        "c2".to_string(),
+        Rc::downgrade(&root),
+        "Fresh Fruit".to_string(),
+        true,
+        Vec::new(),
+    )
+    .unwrap();
+
+    let validation = CategoryNamingConventionValidation::new(Some(NamingConvention {
+        preset: Some(NamingPreset::SnakeCase),
+        ..NamingConvention::default()
+    }));
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[root], &mut diagnostics).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(
+        ErrorKind::NamingConventionViolated,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert_eq!(
+        Some("c2".to_string()),
+        diagnostics.as_slice()[0].category_id
+    );
+}
+
+#[test]
+fn no_errors_without_convention() {
+    let root = Category::new("c1".to_string(), "Food".to_string(), true, Vec::new());
+
+    let validation = CategoryNamingConventionValidation::new(None);
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[root], &mut diagnostics).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::directory_layout_validation::DirectoryLayoutValidation;
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+
+fn categories_path(paths: &[(&str, &str)]) -> HashMap<String, String> {
+    paths
+        .iter()
+        .map(|(id, path)| (id.to_string(), path.to_string()))
+        .collect()
+}
+
+#[test]
+fn no_errors_if_files_mirror_the_category_tree() {
+    let food = Category::new("food-id".to_string(), "food".to_string(), false, Vec::new());
+    Category::new_into_parent(
+        "fruit-id".to_string(),
+        Rc::downgrade(&food),
+        "fruit".to_string(),
+        true,
+        Vec::new(),
+    )
+    .unwrap();
+
+    let validation = DirectoryLayoutValidation::new(
+        "categories/",
+        categories_path(&[
+            ("food-id", "categories/food.json"),
+            ("fruit-id", "categories/food/fruit.json"),
+        ]),
+    );
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[food], &mut diagnostics).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn error_if_file_is_not_within_its_parent_directory() {
+    let food = Category::new("food-id".to_string(), "food".to_string(), false, Vec::new());
+    Category::new_into_parent(
+        "fruit-id".to_string(),
+        Rc::downgrade(&food),
+        "fruit".to_string(),
+        true,
+        Vec::new(),
+    )
+    .unwrap();
+
+    let validation = DirectoryLayoutValidation::new(
+        "categories",
+        categories_path(&[
+            ("food-id", "categories/food.json"),
+            ("fruit-id", "categories/fruit.json"),
+        ]),
+    );
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[food], &mut diagnostics).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(
+        ErrorKind::MisplacedCategoryFile,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert_eq!(
+        Some(ErrorDetails::ExpectedPath {
+            path: "categories/food/fruit.json".to_string()
+        }),
+        diagnostics.as_slice()[0].details
+    );
+}
+
+#[test]
+fn error_if_file_is_not_named_after_the_category() {
+    let food = Category::new("food-id".to_string(), "food".to_string(), true, Vec::new());
+
+    let validation = DirectoryLayoutValidation::new(
+        "categories",
+        categories_path(&[("food-id", "categories/groceries.json")]),
+    );
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[food], &mut diagnostics).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+}
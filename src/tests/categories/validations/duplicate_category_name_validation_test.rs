@@ -0,0 +1,119 @@
+#[cfg(test)]
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::duplicate_category_name_validation::DuplicateCategoryNameValidation;
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+
+fn child(id: &str, parent: &Rc<RefCell<Category>>, name: &str) -> Rc<RefCell<Category>> {
+    match Category::new_into_parent(
+        id.to_string(),
+        Rc::downgrade(parent),
+        name.to_string(),
+        true,
+        Vec::new(),
+    ) {
+        Ok(category) => category,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+fn validate(root_categories: &[Rc<RefCell<Category>>]) -> Diagnostics {
+    let mut categories_path = HashMap::new();
+    categories_path.insert("C1".to_string(), "./categories/food/apple.json".to_string());
+    categories_path.insert(
+        "C3".to_string(),
+        "./categories/drinks/apple.json".to_string(),
+    );
+
+    let mut diagnostics = Diagnostics::new();
+
+    DuplicateCategoryNameValidation::new(categories_path)
+        .validate(root_categories, &mut diagnostics)
+        .unwrap();
+
+    diagnostics
+}
+
+#[test]
+fn reports_every_category_sharing_a_name_across_the_tree() {
+    let food = Category::new("F".to_string(), "food".to_string(), false, Vec::new());
+    let drinks = Category::new("D".to_string(), "drinks".to_string(), false, Vec::new());
+    child("C1", &food, "apple");
+    child("C2", &food, "pear");
+    child("C3", &drinks, "apple");
+
+    let diagnostics = validate(&[food, drinks]);
+
+    assert_eq!(2, diagnostics.len());
+
+    for diagnostic in diagnostics.as_slice() {
+        assert_eq!(ErrorKind::DuplicatedName, diagnostic.kind());
+        assert!(diagnostic
+            .message
+            .contains("C1 (./categories/food/apple.json)"));
+        assert!(diagnostic
+            .message
+            .contains("C3 (./categories/drinks/apple.json)"));
+        assert_eq!(
+            Some(ErrorDetails::Name {
+                name: "apple".to_string(),
+                ids: vec!["C1".to_string(), "C3".to_string()],
+            }),
+            diagnostic.details
+        );
+    }
+
+    assert_eq!(
+        Some("C1".to_string()),
+        diagnostics.as_slice()[0].category_id
+    );
+    assert_eq!(
+        Some("C3".to_string()),
+        diagnostics.as_slice()[1].category_id
+    );
+}
+
+#[test]
+fn reports_siblings_whose_names_only_differ_in_case() {
+    let food = Category::new("F".to_string(), "food".to_string(), false, Vec::new());
+    child("C1", &food, "apple");
+    child("C2", &food, "Apple");
+
+    let diagnostics = validate(&[food]);
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(
+        ErrorKind::DuplicatedSiblingName,
+        diagnostics.as_slice()[0].kind()
+    );
+    assert!(diagnostics.as_slice()[0]
+        .message
+        .starts_with("children of 'food'"));
+}
+
+#[test]
+fn duplicated_siblings_are_only_reported_once() {
+    let first = Category::new("F1".to_string(), "food".to_string(), true, Vec::new());
+    let second = Category::new("F2".to_string(), "food".to_string(), true, Vec::new());
+
+    let diagnostics = validate(&[first, second]);
+
+    assert_eq!(2, diagnostics.len());
+    assert_eq!(ErrorKind::DuplicatedName, diagnostics.as_slice()[0].kind());
+}
+
+#[test]
+fn unique_names_are_valid() {
+    let food = Category::new("F".to_string(), "food".to_string(), false, Vec::new());
+    let drinks = Category::new("D".to_string(), "drinks".to_string(), false, Vec::new());
+    child("C1", &food, "apple");
+    child("C2", &drinks, "Apple");
+
+    assert!(validate(&[food, drinks]).is_empty());
+}
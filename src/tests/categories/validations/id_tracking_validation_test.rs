@@ -1,12 +1,13 @@
 /// This module heavily on the current 'categories' folder.
 /// These tests are expecting only 3 categories: food, fruit and pear.
-#[cfg(test)]
 use std::rc::Rc;
 
 use cooplan_definitions_lib::category::Category;
 
+use crate::categories::category_id_tracker_file_io::CategoryIdTrackerFileIO;
 use crate::categories::validations::id_tracking_validation::IdTrackingValidation;
 use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
 use crate::error::ErrorKind;
 
 #[test]
@@ -18,10 +19,15 @@ fn error_if_missing_categories() {
         Vec::new(),
     );
 
-    let id_tracking_validation = IdTrackingValidation::new();
+    let id_tracking_validation = IdTrackingValidation::new(Rc::new(CategoryIdTrackerFileIO::new(
+        "./category_id_tracker.csv",
+    )));
 
-    assert_eq!(
-        ErrorKind::IdNotTracked,
-        id_tracking_validation.validate(&[food]).unwrap_err().kind()
-    );
+    let mut diagnostics = Diagnostics::new();
+
+    id_tracking_validation
+        .validate(&[food], &mut diagnostics)
+        .unwrap();
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(ErrorKind::IdNotTracked, diagnostics.as_slice()[0].kind());
 }
@@ -1,2 +1,8 @@
+mod category_cycle_validation_test;
+mod category_files_validation_test;
+mod category_naming_convention_validation_test;
+mod directory_layout_validation_test;
+mod duplicate_category_name_validation_test;
+mod id_tracking_validation_test;
+mod reserved_category_names_validation_test;
 mod selectable_as_last_validation_test;
-mod id_tracking_validation_test;
\ No newline at end of file
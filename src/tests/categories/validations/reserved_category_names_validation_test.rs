@@ -0,0 +1,61 @@
+#[cfg(test)]
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::validations::reserved_category_names_validation::ReservedCategoryNamesValidation;
+use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
+use crate::error::{ErrorDetails, ErrorKind};
+use crate::reserved_keywords::{KeywordMatching, ReservedKeyword, ReservedKeywords};
+
+#[test]
+fn error_if_category_uses_a_reserved_keyword() {
+    let root = Category::new("c1".to_string(), "food".to_string(), false, Vec::new());
+    Category::new_into_parent(
+        "c2".to_string(),
+        Rc::downgrade(&root),
+        "Other".to_string(),
+        true,
+        Vec::new(),
+    )
+    .unwrap();
+
+    let validation = ReservedCategoryNamesValidation::new(ReservedKeywords::new(
+        vec![ReservedKeyword::Explained {
+            keyword: "other".to_string(),
+            explanation: "uncategorized products have no category".to_string(),
+        }],
+        KeywordMatching::CaseInsensitive,
+    ));
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[root], &mut diagnostics).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+
+    let diagnostic = &diagnostics.as_slice()[0];
+    assert_eq!(ErrorKind::ReservedKeywordUsedAsName, diagnostic.kind());
+    assert!(diagnostic
+        .message
+        .contains("uncategorized products have no category"));
+    assert_eq!(
+        Some(ErrorDetails::ReservedKeyword {
+            keyword: "other".to_string(),
+            explanation: Some("uncategorized products have no category".to_string()),
+        }),
+        diagnostic.details
+    );
+}
+
+#[test]
+fn no_errors_without_reserved_keywords() {
+    let root = Category::new("c1".to_string(), "other".to_string(), true, Vec::new());
+
+    let validation = ReservedCategoryNamesValidation::new(Vec::new());
+    let mut diagnostics = Diagnostics::new();
+
+    validation.validate(&[root], &mut diagnostics).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
@@ -6,6 +6,7 @@ use cooplan_definitions_lib::category::Category;
 
 use crate::categories::validations::selectable_as_last_validation::SelectableAsLastValidation;
 use crate::categories::validations::validation::Validation;
+use crate::diagnostics::Diagnostics;
 use crate::error::ErrorKind;
 
 #[test]
@@ -15,14 +16,17 @@ fn error_if_not_selectable_as_last_and_has_no_children() {
     let slice: &[Rc<RefCell<Category>>] = &[invalid_category];
 
     let selectable_as_last_validation = SelectableAsLastValidation::new();
+    let mut diagnostics = Diagnostics::new();
 
-    selectable_as_last_validation.validate(slice).unwrap_err();
+    selectable_as_last_validation
+        .validate(slice, &mut diagnostics)
+        .unwrap();
+    assert_eq!(1, diagnostics.len());
 }
 
 #[test]
 fn error_if_children_not_selectable_as_last() {
-    let mut parent_category =
-        Category::new("ABCD".to_string(), "ABCD".to_string(), false, Vec::new());
+    let parent_category = Category::new("ABCD".to_string(), "ABCD".to_string(), false, Vec::new());
 
     match Category::new_into_parent(
         "C1".to_string(),
@@ -51,10 +55,13 @@ fn error_if_children_not_selectable_as_last() {
     }
 
     let selectable_as_last_validation = SelectableAsLastValidation::new();
+    let mut diagnostics = Diagnostics::new();
 
     selectable_as_last_validation
-        .validate(&[parent_category])
-        .unwrap_err();
+        .validate(&[parent_category], &mut diagnostics)
+        .unwrap();
+    // Both children are reported, not only the first one.
+    assert_eq!(2, diagnostics.len());
 }
 
 #[test]
@@ -62,10 +69,12 @@ fn detects_correct_category_correctly() {
     let valid_category = Category::new("ABCD".to_string(), "ABCD".to_string(), true, Vec::new());
 
     let selectable_as_last_validation = SelectableAsLastValidation::new();
+    let mut diagnostics = Diagnostics::new();
 
     selectable_as_last_validation
-        .validate(&[valid_category])
+        .validate(&[valid_category], &mut diagnostics)
         .unwrap();
+    assert!(diagnostics.is_empty());
 }
 
 #[test]
@@ -101,7 +110,7 @@ fn detects_correct_expanded_category_tree_correctly() {
         Vec::new(),
     );
 
-    let forth_inner_category = Category::new_into_parent(
+    let _forth_inner_category = Category::new_into_parent(
         "inner 4".to_string(),
         Rc::downgrade(&(third_inner_category.unwrap())),
         "inner 4".to_string(),
@@ -109,7 +118,7 @@ fn detects_correct_expanded_category_tree_correctly() {
         Vec::new(),
     );
 
-    let third_alt_inner_category = Category::new_into_parent(
+    let _third_alt_inner_category = Category::new_into_parent(
         "inner 3 alt".to_string(),
         Rc::downgrade(&second_inner_category),
         "inner 3 alt".to_string(),
@@ -117,7 +126,7 @@ fn detects_correct_expanded_category_tree_correctly() {
         Vec::new(),
     );
 
-    let second_alt_inner_category = Category::new_into_parent(
+    let _second_alt_inner_category = Category::new_into_parent(
         "inner 2 alt".to_string(),
         Rc::downgrade(&root_category),
         "inner 2 alt".to_string(),
@@ -126,10 +135,12 @@ fn detects_correct_expanded_category_tree_correctly() {
     );
 
     let selectable_as_last_validation = SelectableAsLastValidation::new();
+    let mut diagnostics = Diagnostics::new();
 
     selectable_as_last_validation
-        .validate(&[root_category])
+        .validate(&[root_category], &mut diagnostics)
         .unwrap();
+    assert!(diagnostics.is_empty());
 }
 
 #[test]
@@ -165,7 +176,7 @@ fn error_if_there_is_a_non_selectable_last_category() {
         Vec::new(),
     );
 
-    let forth_inner_category = Category::new_into_parent(
+    let _forth_inner_category = Category::new_into_parent(
         "inner 4".to_string(),
         Rc::downgrade(&(third_inner_category.unwrap())),
         "inner 4".to_string(),
@@ -173,7 +184,7 @@ fn error_if_there_is_a_non_selectable_last_category() {
         Vec::new(),
     );
 
-    let third_alt_inner_category = Category::new_into_parent(
+    let _third_alt_inner_category = Category::new_into_parent(
         "inner 3 alt".to_string(),
         Rc::downgrade(&second_inner_category),
         "inner 3 alt".to_string(),
@@ -181,7 +192,7 @@ fn error_if_there_is_a_non_selectable_last_category() {
         Vec::new(),
     );
 
-    let second_alt_inner_category = Category::new_into_parent(
+    let _second_alt_inner_category = Category::new_into_parent(
         "inner 2 alt".to_string(),
         Rc::downgrade(&root_category),
         "inner 2 alt".to_string(),
@@ -190,12 +201,14 @@ fn error_if_there_is_a_non_selectable_last_category() {
     );
 
     let selectable_as_last_validation = SelectableAsLastValidation::new();
+    let mut diagnostics = Diagnostics::new();
 
+    selectable_as_last_validation
+        .validate(&[root_category], &mut diagnostics)
+        .unwrap();
+    assert_eq!(1, diagnostics.len());
     assert_eq!(
         ErrorKind::LastCategoryNotSelectable,
-        selectable_as_last_validation
-            .validate(&[root_category])
-            .unwrap_err()
-            .kind()
+        diagnostics.as_slice()[0].kind()
     );
 }
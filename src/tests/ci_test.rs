@@ -0,0 +1,769 @@
+#[cfg(test)]
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde_json::json;
+
+use crate::attributes::attribute_tracker_io::{
+    AllowedAttributeChange, AttributeEntry, AttributeTrackerIO, TrackedField,
+};
+use crate::attributes::attribute_tracker_memory_io::AttributeTrackerMemoryIO;
+use crate::attributes::constraints::Constraints;
+use crate::attributes::option_tracker_io::OptionTrackerIO;
+use crate::attributes::option_tracker_memory_io::OptionTrackerMemoryIO;
+use crate::attributes::validations::data_type_validation::DataTypeValidation;
+use crate::categories::category_id_tracker::CategoryEntry;
+use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
+use crate::categories::category_id_tracker_memory_io::CategoryIdTrackerMemoryIO;
+use crate::categories::validations::selectable_as_last_validation::SelectableAsLastValidation;
+use crate::categories::validations::validation::Validation;
+use crate::ci::CI;
+use crate::config::Config;
+use crate::data_type_widenings::DataTypeWidenings;
+use crate::error::ErrorKind;
+use crate::paths::Paths;
+use crate::tests::test_files::create_test_directory;
+
+const FOOD_JSON: &str = r#"{
+  "id": "food-id",
+  "parent": null,
+  "name": "food",
+  "selectable_as_last": true,
+  "attributes": [
+    {
+      "name": "weight",
+      "data_type": "decimal",
+      "unit": null
+    }
+  ]
+}"#;
+
+fn create_ci(
+    test_name: &str,
+) -> (
+    CI,
+    Rc<CategoryIdTrackerMemoryIO>,
+    Rc<AttributeTrackerMemoryIO>,
+) {
+    create_ci_with_files(test_name, &[("food.json", FOOD_JSON)], &["food-id"])
+}
+
+/// Creates a CI whose categories are the given files, relative to the categories' root directory.
+fn create_ci_with_files(
+    test_name: &str,
+    files: &[(&str, &str)],
+    tracked_category_ids: &[&str],
+) -> (
+    CI,
+    Rc<CategoryIdTrackerMemoryIO>,
+    Rc<AttributeTrackerMemoryIO>,
+) {
+    create_ci_with_config(
+        test_name,
+        files,
+        tracked_category_ids,
+        Config::new(vec!["decimal".to_string()], Vec::new()),
+    )
+}
+
+fn create_ci_with_config(
+    test_name: &str,
+    files: &[(&str, &str)],
+    tracked_category_ids: &[&str],
+    config: Config,
+) -> (
+    CI,
+    Rc<CategoryIdTrackerMemoryIO>,
+    Rc<AttributeTrackerMemoryIO>,
+) {
+    let directory = create_test_directory(test_name);
+
+    for (file, content) in files {
+        let file_path = directory.join(file);
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        std::fs::write(file_path, content).unwrap();
+    }
+
+    let mut paths = Paths::default();
+    paths.set_categories_root(directory.to_str().unwrap());
+
+    let mut category_entries = HashMap::new();
+
+    for id in tracked_category_ids {
+        category_entries.insert(id.to_string(), CategoryEntry { id: id.to_string() });
+    }
+
+    let category_id_tracker_io = Rc::new(CategoryIdTrackerMemoryIO::new(category_entries));
+    let attribute_tracker_io = Rc::new(AttributeTrackerMemoryIO::new(HashMap::new()));
+
+    let ci = CI::new(
+        paths,
+        config,
+        Rc::clone(&category_id_tracker_io) as Rc<dyn CategoryIdTrackerIO>,
+        Rc::clone(&attribute_tracker_io) as Rc<dyn AttributeTrackerIO>,
+    );
+
+    (ci, category_id_tracker_io, attribute_tracker_io)
+}
+
+#[test]
+fn load_generates_ids_without_writing_them() {
+    let (mut ci, _, attribute_tracker_io) = create_ci("load_generates_ids_without_writing_them");
+
+    ci.load().unwrap();
+
+    assert_eq!(1, ci.root_categories().len());
+    assert_eq!(1, ci.generated_ids().len());
+    assert_eq!(
+        Some("weight".to_string()),
+        ci.generated_ids()[0].attribute.clone()
+    );
+    assert!(attribute_tracker_io.read_entries().unwrap().is_empty());
+}
+
+#[test]
+fn validates_with_chosen_validations() {
+    let (mut ci, _, _) = create_ci("validates_with_chosen_validations");
+    ci.load().unwrap();
+
+    let category_validations: Vec<Box<dyn Validation>> =
+        vec![Box::new(SelectableAsLastValidation::new())];
+    let attribute_validations = ci.enabled_attribute_validations().unwrap();
+
+    ci.validate(
+        category_validations.as_slice(),
+        attribute_validations.as_slice(),
+    )
+    .unwrap();
+
+    assert!(ci.diagnostics().is_empty());
+}
+
+#[test]
+fn reports_diagnostics_of_failed_validations() {
+    let (mut ci, _, _) = create_ci("reports_diagnostics_of_failed_validations");
+    ci.load().unwrap();
+
+    let category_validations = ci.enabled_category_validations().unwrap();
+    let mut attribute_validations = ci.enabled_attribute_validations().unwrap();
+    attribute_validations.push(Rc::new(RefCell::new(DataTypeValidation::new(vec![
+        "string".to_string(),
+    ]))));
+
+    let error = ci
+        .validate(
+            category_validations.as_slice(),
+            attribute_validations.as_slice(),
+        )
+        .unwrap_err();
+
+    assert_eq!(ErrorKind::ValidationFailed, error.kind());
+    assert_eq!(1, ci.diagnostics().len());
+    assert_eq!(
+        ErrorKind::FailedDataTypeAttributeValidation,
+        ci.diagnostics().as_slice()[0].kind()
+    );
+}
+
+#[test]
+fn apply_generated_ids_writes_tracked_entries() {
+    let (mut ci, _, attribute_tracker_io) = create_ci("apply_generated_ids_writes_tracked_entries");
+    ci.load().unwrap();
+
+    ci.apply_generated_ids().unwrap();
+
+    let entries = attribute_tracker_io.read_entries().unwrap();
+    let generated_id = ci.generated_ids()[0].id.clone();
+
+    assert_eq!(1, entries.len());
+    assert_eq!("decimal", entries.get(&generated_id).unwrap().data_type);
+}
+
+#[test]
+fn resolves_parent_by_id_after_the_parent_has_been_renamed() {
+    let renamed_food = FOOD_JSON.replace("\"name\": \"food\"", "\"name\": \"groceries\"");
+    let (mut ci, _, _) = create_ci_with_files(
+        "resolves_parent_by_id_after_the_parent_has_been_renamed",
+        &[
+            ("food.json", renamed_food.as_str()),
+            (
+                "food/fruit.json",
+                r#"{"id": "fruit-id", "parent": "food-id", "name": "fruit", "attributes": []}"#,
+            ),
+        ],
+        &["food-id", "fruit-id"],
+    );
+
+    ci.load().unwrap();
+
+    let root_category = ci.root_categories()[0].borrow();
+    assert_eq!("groceries", root_category.name);
+    assert_eq!("fruit-id", root_category.children[0].borrow().id);
+}
+
+#[test]
+fn resolves_parent_by_name_for_legacy_files() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "resolves_parent_by_name_for_legacy_files",
+        &[
+            ("food.json", FOOD_JSON),
+            (
+                "food/fruit.json",
+                r#"{"id": "fruit-id", "name": "fruit", "attributes": []}"#,
+            ),
+        ],
+        &["food-id", "fruit-id"],
+    );
+
+    ci.load().unwrap();
+
+    assert_eq!(1, ci.root_categories()[0].borrow().children.len());
+}
+
+#[test]
+fn error_if_parent_is_not_tracked() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "error_if_parent_is_not_tracked",
+        &[
+            ("food.json", FOOD_JSON),
+            (
+                "food/fruit.json",
+                r#"{"id": "fruit-id", "parent": "removed-id", "name": "fruit", "attributes": []}"#,
+            ),
+        ],
+        &["food-id", "fruit-id"],
+    );
+
+    let error = ci.load().unwrap_err();
+
+    assert_eq!(ErrorKind::ParentNotFound, error.kind());
+    assert!(error.message.contains("category 'fruit' defined in"));
+    assert!(error.message.contains("removed-id"));
+    assert!(error
+        .location()
+        .unwrap()
+        .file_path
+        .ends_with("food/fruit.json"));
+}
+
+#[test]
+fn maps_children_read_before_their_parents() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "maps_children_read_before_their_parents",
+        &[
+            (
+                "a/apple.json",
+                r#"{"id": "apple-id", "parent": "zucchini-id", "name": "apple", "attributes": []}"#,
+            ),
+            (
+                "zucchini.json",
+                r#"{"id": "zucchini-id", "parent": null, "name": "zucchini", "attributes": []}"#,
+            ),
+        ],
+        &["apple-id", "zucchini-id"],
+    );
+
+    ci.load().unwrap();
+
+    assert_eq!(1, ci.root_categories().len());
+    assert_eq!(
+        "apple-id",
+        ci.root_categories()[0].borrow().children[0].borrow().id
+    );
+}
+
+#[test]
+fn error_if_parent_is_not_among_the_categories() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "error_if_parent_is_not_among_the_categories",
+        &[(
+            "food/fruit.json",
+            r#"{"id": "fruit-id", "parent": "food-id", "name": "fruit", "attributes": []}"#,
+        )],
+        &["food-id", "fruit-id"],
+    );
+
+    let error = ci.load().unwrap_err();
+
+    assert_eq!(ErrorKind::ParentNotFound, error.kind());
+    assert!(error.message.contains("not among the categories"));
+}
+
+#[test]
+fn error_if_categories_form_a_cycle() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "error_if_categories_form_a_cycle",
+        &[
+            (
+                "a.json",
+                r#"{"id": "a-id", "parent": "b-id", "name": "a", "attributes": []}"#,
+            ),
+            (
+                "b.json",
+                r#"{"id": "b-id", "parent": "a-id", "name": "b", "attributes": []}"#,
+            ),
+        ],
+        &["a-id", "b-id"],
+    );
+
+    let error = ci.load().unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidCategoryTree, error.kind());
+    assert_eq!(2, error.errors().len());
+
+    for cycle_error in error.errors() {
+        assert_eq!(ErrorKind::CategoryCycle, cycle_error.kind());
+        assert!(cycle_error.message.contains("a-id"));
+        assert!(cycle_error.message.contains("b-id"));
+        assert!(cycle_error.location().is_some());
+    }
+
+    let category_ids: Vec<Option<&str>> = ci
+        .diagnostics()
+        .as_slice()
+        .iter()
+        .map(|diagnostic| diagnostic.category_id.as_deref())
+        .collect();
+    assert_eq!(vec![Some("a-id"), Some("b-id")], category_ids);
+}
+
+#[test]
+fn fix_directory_layout_moves_misplaced_files() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "fix_directory_layout_moves_misplaced_files",
+        &[
+            ("food.json", FOOD_JSON),
+            (
+                "fruit.json",
+                r#"{"id": "fruit-id", "parent": "food-id", "name": "fruit", "attributes": []}"#,
+            ),
+        ],
+        &["food-id", "fruit-id"],
+    );
+    ci.load().unwrap();
+
+    ci.fix_directory_layout().unwrap();
+
+    assert_eq!(1, ci.moved_files().len());
+
+    let moved_file = &ci.moved_files()[0];
+    assert!(moved_file.from.ends_with("fruit.json"));
+    assert!(moved_file.to.ends_with("food/fruit.json"));
+    assert!(!std::path::Path::new(moved_file.from.as_str()).exists());
+    assert!(std::path::Path::new(moved_file.to.as_str()).exists());
+    assert_eq!(Some(moved_file.to.as_str()), ci.category_path("fruit-id"));
+}
+
+#[test]
+fn fix_directory_layout_removes_the_directories_left_empty() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "fix_directory_layout_removes_the_directories_left_empty",
+        &[
+            ("food.json", FOOD_JSON),
+            (
+                "food/fruit.json",
+                r#"{"id": "fruit-id", "parent": "food-id", "name": "fruits", "selectable_as_last": false, "attributes": []}"#,
+            ),
+            (
+                "food/fruit/apple.json",
+                r#"{"id": "apple-id", "parent": "fruit-id", "name": "apple", "selectable_as_last": true, "attributes": []}"#,
+            ),
+        ],
+        &["food-id", "fruit-id", "apple-id"],
+    );
+    ci.set_fix_layout(true);
+
+    ci.run_ci_logic().unwrap();
+
+    assert_eq!(2, ci.moved_files().len());
+
+    let apple_path = std::path::Path::new(ci.category_path("apple-id").unwrap());
+    assert!(apple_path.ends_with("food/fruits/apple.json"));
+    assert!(apple_path.exists());
+    assert!(!apple_path
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("fruit")
+        .exists());
+    assert_eq!(0, ci.diagnostics().as_slice().len());
+}
+
+#[test]
+fn reports_every_category_that_cannot_be_read() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "reports_every_category_that_cannot_be_read",
+        &[
+            ("food.json", FOOD_JSON),
+            ("drinks.json", "{"),
+            ("food/fruit.json", "{"),
+        ],
+        &["food-id"],
+    );
+
+    ci.load().unwrap();
+
+    let diagnostics = ci.diagnostics().as_slice();
+
+    assert_eq!(2, diagnostics.len());
+    assert!(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.kind() == ErrorKind::InvalidCategoryFile));
+    assert_eq!(Some(1), diagnostics[0].line);
+}
+
+#[test]
+fn check_reports_invalid_and_stray_files_in_the_same_run() {
+    let (mut ci, _, _) = create_ci_with_files(
+        "check_reports_invalid_and_stray_files_in_the_same_run",
+        &[
+            ("food.json", FOOD_JSON),
+            ("food/fruit.json", "{\n  \"name\": }"),
+            ("food/notes.txt", "notes"),
+        ],
+        &["food-id"],
+    );
+
+    let error = ci.run_check_logic().unwrap_err();
+
+    assert_eq!(ErrorKind::ValidationFailed, error.kind());
+
+    let kinds: Vec<ErrorKind> = ci
+        .diagnostics()
+        .as_slice()
+        .iter()
+        .map(|diagnostic| diagnostic.kind())
+        .collect();
+
+    assert_eq!(
+        vec![ErrorKind::InvalidCategoryFile, ErrorKind::StrayFile],
+        kinds
+    );
+}
+
+const TRACKED_FOOD_JSON: &str = r#"{
+  "id": "food-id",
+  "parent": null,
+  "name": "food",
+  "selectable_as_last": true,
+  "attributes": [
+    {
+      "id": "weight-id",
+      "name": "weight",
+      "data_type": "decimal",
+      "unit": null
+    }
+  ]
+}"#;
+
+fn weight_entry(name: Option<&str>) -> AttributeEntry {
+    AttributeEntry {
+        id: "weight-id".to_string(),
+        data_type: "decimal".to_string(),
+        unit: name.map(|_| "".to_string()),
+        name: name.map(|name| name.to_string()),
+        category_id: name.map(|_| "food-id".to_string()),
+        constraints: name.map(|_| Constraints::default()),
+        optional: name.map(|_| false),
+    }
+}
+
+#[test]
+fn migrates_attribute_entries_that_predate_the_tracking_of_fields() {
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_files(
+        "migrates_attribute_entries_that_predate_the_tracking_of_fields",
+        &[("food.json", TRACKED_FOOD_JSON)],
+        &["food-id"],
+    );
+    attribute_tracker_io
+        .write_entry(&weight_entry(None))
+        .unwrap();
+    ci.load().unwrap();
+
+    ci.apply_generated_ids().unwrap();
+
+    let entries = attribute_tracker_io.read_entries().unwrap();
+    let entry = entries.get("weight-id").unwrap();
+    assert_eq!(Some("".to_string()), entry.unit);
+    assert_eq!(Some("weight".to_string()), entry.name);
+    assert_eq!(Some("food-id".to_string()), entry.category_id);
+}
+
+#[test]
+fn check_fails_if_any_file_would_change() {
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_files(
+        "check_fails_if_any_file_would_change",
+        &[("food.json", TRACKED_FOOD_JSON)],
+        &["food-id"],
+    );
+    attribute_tracker_io
+        .write_entry(&weight_entry(Some("weight")))
+        .unwrap();
+
+    // The file lacks fields that would be written, e.g. whether the attribute is optional.
+    let error = ci.run_check_logic().unwrap_err();
+    assert_eq!(ErrorKind::PendingChanges, error.kind());
+    assert!(error.message.contains("food.json"));
+
+    ci.run_ci_logic().unwrap();
+    ci.run_check_logic().unwrap();
+
+    attribute_tracker_io
+        .write_entry(&weight_entry(None))
+        .unwrap();
+
+    let error = ci.run_check_logic().unwrap_err();
+    assert_eq!(ErrorKind::PendingChanges, error.kind());
+    assert!(error.message.contains("attribute_id_tracker.csv"));
+}
+
+#[test]
+fn check_reports_pending_changes_alongside_failed_validations() {
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_files(
+        "check_reports_pending_changes_alongside_failed_validations",
+        &[(
+            "food.json",
+            TRACKED_FOOD_JSON.replace("\"weight\"", "\"mass\"").as_str(),
+        )],
+        &["food-id"],
+    );
+    attribute_tracker_io
+        .write_entry(&weight_entry(Some("weight")))
+        .unwrap();
+
+    let error = ci.run_check_logic().unwrap_err();
+
+    assert_eq!(ErrorKind::ValidationFailed, error.kind());
+    assert_eq!(2, error.errors().len());
+    assert_eq!(ErrorKind::ValidationFailed, error.errors()[0].kind());
+    assert_eq!(ErrorKind::PendingChanges, error.errors()[1].kind());
+    assert!(error.errors()[1].message.contains("food.json"));
+}
+
+#[test]
+fn tracks_allowed_attribute_changes() {
+    let mut config = Config::new(vec!["decimal".to_string()], Vec::new());
+    config.allow_attribute_change(
+        "weight-id",
+        AllowedAttributeChange::new(TrackedField::Name, json!("mass")),
+    );
+
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_config(
+        "tracks_allowed_attribute_changes",
+        &[(
+            "food.json",
+            TRACKED_FOOD_JSON.replace("\"weight\"", "\"mass\"").as_str(),
+        )],
+        &["food-id"],
+        config,
+    );
+    attribute_tracker_io
+        .write_entry(&weight_entry(Some("weight")))
+        .unwrap();
+    ci.load().unwrap();
+
+    let category_validations = ci.enabled_category_validations().unwrap();
+    let attribute_validations = ci.enabled_attribute_validations().unwrap();
+    ci.validate(
+        category_validations.as_slice(),
+        attribute_validations.as_slice(),
+    )
+    .unwrap();
+    ci.apply_generated_ids().unwrap();
+
+    let entries = attribute_tracker_io.read_entries().unwrap();
+    assert_eq!(
+        Some("mass".to_string()),
+        entries.get("weight-id").unwrap().name
+    );
+}
+
+#[test]
+fn error_if_attribute_changes_into_another_value_than_the_allowed_one() {
+    let mut config = Config::new(vec!["decimal".to_string()], Vec::new());
+    config.allow_attribute_change(
+        "weight-id",
+        AllowedAttributeChange::new(TrackedField::Name, json!("mass")),
+    );
+
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_config(
+        "error_if_attribute_changes_into_another_value_than_the_allowed_one",
+        &[(
+            "food.json",
+            TRACKED_FOOD_JSON.replace("\"weight\"", "\"heft\"").as_str(),
+        )],
+        &["food-id"],
+        config,
+    );
+    attribute_tracker_io
+        .write_entry(&weight_entry(Some("mass")))
+        .unwrap();
+    ci.load().unwrap();
+
+    let category_validations = ci.enabled_category_validations().unwrap();
+    let attribute_validations = ci.enabled_attribute_validations().unwrap();
+    ci.validate(
+        category_validations.as_slice(),
+        attribute_validations.as_slice(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        ErrorKind::AttributeRenamed,
+        ci.diagnostics().as_slice()[0].kind()
+    );
+}
+
+#[test]
+fn error_if_tracked_attribute_changes_without_being_allowed() {
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_files(
+        "error_if_tracked_attribute_changes_without_being_allowed",
+        &[(
+            "food.json",
+            TRACKED_FOOD_JSON.replace("\"weight\"", "\"mass\"").as_str(),
+        )],
+        &["food-id"],
+    );
+    attribute_tracker_io
+        .write_entry(&weight_entry(Some("weight")))
+        .unwrap();
+    ci.load().unwrap();
+
+    let category_validations = ci.enabled_category_validations().unwrap();
+    let attribute_validations = ci.enabled_attribute_validations().unwrap();
+    let error = ci
+        .validate(
+            category_validations.as_slice(),
+            attribute_validations.as_slice(),
+        )
+        .unwrap_err();
+
+    assert_eq!(ErrorKind::ValidationFailed, error.kind());
+    assert_eq!(
+        ErrorKind::AttributeRenamed,
+        ci.diagnostics().as_slice()[0].kind()
+    );
+}
+
+#[test]
+fn tracks_widened_data_types() {
+    let mut config = Config::new(
+        vec!["integer".to_string(), "decimal".to_string()],
+        Vec::new(),
+    );
+    config.set_data_type_widenings(DataTypeWidenings::new(
+        vec![("integer".to_string(), vec!["decimal".to_string()])]
+            .into_iter()
+            .collect(),
+    ));
+
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_config(
+        "tracks_widened_data_types",
+        &[("food.json", TRACKED_FOOD_JSON)],
+        &["food-id"],
+        config,
+    );
+    let mut entry = weight_entry(Some("weight"));
+    entry.data_type = "integer".to_string();
+    attribute_tracker_io.write_entry(&entry).unwrap();
+    ci.load().unwrap();
+
+    let category_validations = ci.enabled_category_validations().unwrap();
+    let attribute_validations = ci.enabled_attribute_validations().unwrap();
+    ci.validate(
+        category_validations.as_slice(),
+        attribute_validations.as_slice(),
+    )
+    .unwrap();
+    ci.apply_generated_ids().unwrap();
+
+    let entries = attribute_tracker_io.read_entries().unwrap();
+    assert_eq!("decimal", entries.get("weight-id").unwrap().data_type);
+}
+
+#[test]
+fn tracks_loosened_constraints() {
+    let constrained_food = TRACKED_FOOD_JSON.replace(
+        r#""unit": null"#,
+        r#""unit": null,
+      "min": 0"#,
+    );
+    let (mut ci, _, attribute_tracker_io) = create_ci_with_files(
+        "tracks_loosened_constraints",
+        &[("food.json", constrained_food.as_str())],
+        &["food-id"],
+    );
+    let mut entry = weight_entry(Some("weight"));
+    entry.constraints = Some(Constraints {
+        min: Some(1.into()),
+        max: Some(10.into()),
+        ..Default::default()
+    });
+    attribute_tracker_io.write_entry(&entry).unwrap();
+    ci.load().unwrap();
+
+    let category_validations = ci.enabled_category_validations().unwrap();
+    let attribute_validations = ci.enabled_attribute_validations().unwrap();
+    ci.validate(
+        category_validations.as_slice(),
+        attribute_validations.as_slice(),
+    )
+    .unwrap();
+    ci.apply_generated_ids().unwrap();
+
+    let entries = attribute_tracker_io.read_entries().unwrap();
+    assert_eq!(
+        Some(Constraints {
+            min: Some(0.into()),
+            ..Default::default()
+        }),
+        entries.get("weight-id").unwrap().constraints
+    );
+}
+
+#[test]
+fn generates_tracked_ids_for_options_and_keeps_them_within_the_file() {
+    let enum_food = r#"{
+  "id": "food-id",
+  "parent": null,
+  "name": "food",
+  "attributes": [
+    {
+      "name": "size",
+      "data_type": "enum",
+      "unit": null,
+      "options": [{ "name": "small" }, { "name": "large" }]
+    }
+  ]
+}"#;
+    let (mut ci, _, _) = create_ci_with_files(
+        "generates_tracked_ids_for_options_and_keeps_them_within_the_file",
+        &[("food.json", enum_food)],
+        &["food-id"],
+    );
+    let option_tracker_io = Rc::new(OptionTrackerMemoryIO::new(HashMap::new()));
+    ci.set_option_tracker_io(Rc::clone(&option_tracker_io) as Rc<dyn OptionTrackerIO>);
+    ci.load().unwrap();
+
+    ci.apply_generated_ids().unwrap();
+
+    let generated_options: Vec<&str> = ci
+        .generated_ids()
+        .iter()
+        .filter_map(|generated_id| generated_id.option.as_deref())
+        .collect();
+    assert_eq!(vec!["small", "large"], generated_options);
+    assert_eq!(2, option_tracker_io.read_entries().unwrap().len());
+
+    let content = std::fs::read_to_string(ci.category_path("food-id").unwrap()).unwrap();
+    let category: serde_json::Value = serde_json::from_str(content.as_str()).unwrap();
+    let option_id = category["attributes"][0]["options"][0]["id"]
+        .as_str()
+        .unwrap();
+    assert_eq!(
+        "small",
+        option_tracker_io.read_entries().unwrap()[option_id].name
+    );
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use crate::data_type_widenings::DataTypeWidenings;
+
+fn widenings(data_types: &[(&str, &[&str])]) -> DataTypeWidenings {
+    let data_types: HashMap<String, Vec<String>> = data_types
+        .iter()
+        .map(|(data_type, wider_data_types)| {
+            (
+                data_type.to_string(),
+                wider_data_types
+                    .iter()
+                    .map(|wider_data_type| wider_data_type.to_string())
+                    .collect(),
+            )
+        })
+        .collect();
+
+    DataTypeWidenings::new(data_types)
+}
+
+#[test]
+fn compares_the_items_of_lists() {
+    let widenings = widenings(&[("list<integer>", &["list<decimal>"])]);
+
+    assert!(widenings.allows("list<integer>", "list< decimal >"));
+    assert!(!widenings.allows("list<string>", "list<boolean>"));
+    assert!(!widenings.allows("integer", "decimal"));
+}
+
+#[test]
+fn rejects_widenings_into_narrower_bounds() {
+    let widenings = widenings(&[
+        ("integer", &["decimal"]),
+        ("string(max=64)", &["string(max=128)"]),
+        (
+            "decimal(precision=10,scale=2)",
+            &["decimal(precision=20,scale=2)"],
+        ),
+    ]);
+
+    assert!(widenings.allows("integer", "decimal"));
+    assert!(!widenings.allows("integer", "decimal(precision=3,scale=2)"));
+    assert!(widenings.allows("string(max=32)", "string(max=256)"));
+    assert!(!widenings.allows("string(max=64)", "string(max=100)"));
+    assert!(!widenings.allows("string", "string(max=128)"));
+    assert!(widenings.allows(
+        "decimal(precision=10, scale=2)",
+        "decimal(precision=20,scale=2)"
+    ));
+    assert!(!widenings.allows(
+        "decimal(precision=10,scale=2)",
+        "decimal(precision=20,scale=4)"
+    ));
+}
+
+#[test]
+fn compares_the_options_of_enums() {
+    let widenings = widenings(&[
+        ("enum[red,green]", &["enum[red,green,blue]"]),
+        ("enum", &["string"]),
+    ]);
+
+    assert!(widenings.allows("enum[red]", "enum[red,green,blue,black]"));
+    assert!(!widenings.allows("enum[red,green]", "enum[red,blue]"));
+    assert!(widenings.allows("enum[red,green]", "string"));
+}
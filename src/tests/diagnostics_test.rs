@@ -0,0 +1,97 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::{attribute::Attribute, category::Category};
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::ErrorKind;
+use crate::source_location::SourceLocator;
+use crate::tests::test_files::create_test_directory;
+
+const CATEGORY_JSON: &str = r#"{
+  "id": "category-id",
+  "parent": null,
+  "name": "food",
+  "attributes": [
+    {
+      "id": "attribute-id",
+      "name": "expiration_date",
+      "data_type": "date",
+      "unit": null
+    },
+    {
+      "name": "weight",
+      "data_type": "decimal",
+      "unit": null
+    }
+  ]
+}"#;
+
+#[test]
+fn resolves_locations_of_category_and_attribute_diagnostics() {
+    let directory = create_test_directory("resolves_locations");
+    let file_path = directory.join("food.json");
+    std::fs::write(&file_path, CATEGORY_JSON).unwrap();
+    let file_path = file_path.to_str().unwrap().to_string();
+
+    let expiration_date = Attribute {
+        id: "attribute-id".to_string(),
+        name: "expiration_date".to_string(),
+        data_type: "date".to_string(),
+        unit: None,
+        optional: false,
+    };
+    let weight = Attribute {
+        id: "not-written-yet".to_string(),
+        name: "weight".to_string(),
+        data_type: "decimal".to_string(),
+        unit: None,
+        optional: false,
+    };
+    let category = Category::new(
+        "category-id".to_string(),
+        "food".to_string(),
+        false,
+        vec![expiration_date.clone(), weight.clone()],
+    );
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push(Diagnostic::for_category(
+        ErrorKind::LastCategoryNotSelectable,
+        "not selectable",
+        &category.borrow(),
+    ));
+    diagnostics.push(Diagnostic::for_attribute(
+        ErrorKind::FailedDataTypeAttributeValidation,
+        "invalid data type",
+        &category.borrow(),
+        &expiration_date,
+    ));
+    diagnostics.push(Diagnostic::for_attribute(
+        ErrorKind::FailedDataTypeAttributeValidation,
+        "invalid data type",
+        &category.borrow(),
+        &weight,
+    ));
+    diagnostics.push(Diagnostic::new(ErrorKind::IdNotTracked, "not tracked"));
+
+    let mut categories_path: HashMap<String, String> = HashMap::new();
+    categories_path.insert("category-id".to_string(), file_path.clone());
+
+    diagnostics.resolve_locations(&categories_path, &mut SourceLocator::new());
+
+    let category_diagnostic = &diagnostics.as_slice()[0];
+    assert_eq!(Some(file_path.clone()), category_diagnostic.file_path);
+    assert_eq!(Some(2), category_diagnostic.line);
+    assert_eq!(Some(3), category_diagnostic.column);
+
+    let attribute_diagnostic = &diagnostics.as_slice()[1];
+    assert_eq!(Some(7), attribute_diagnostic.line);
+    assert_eq!(Some(7), attribute_diagnostic.column);
+
+    // Attributes whose id has not been written yet are located by their name.
+    let unwritten_attribute_diagnostic = &diagnostics.as_slice()[2];
+    assert_eq!(Some(13), unwritten_attribute_diagnostic.line);
+
+    assert_eq!(None, diagnostics.as_slice()[3].file_path);
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+use crate::diagnostics::Diagnostic;
+use crate::error::{Error, ErrorDetails, ErrorKind};
+use crate::source_location::SourceLocation;
+
+#[test]
+fn exposes_underlying_error_as_source() {
+    let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
+    let error = Error::new(
+        ErrorKind::FailedToReadConfig,
+        "failed to read configuration file",
+    )
+    .with_source(io_error);
+
+    let source = std::error::Error::source(&error).unwrap();
+
+    assert_eq!("file not found", source.to_string());
+    assert_eq!("failed to read configuration file", error.to_string());
+    assert_eq!(
+        "failed to read configuration file: file not found",
+        error.full_message()
+    );
+}
+
+#[test]
+fn alternate_display_lists_aggregated_errors() {
+    let error = Error::aggregate(
+        ErrorKind::ValidationFailed,
+        "validation failed with 2 problem(s)",
+        vec![
+            Error::new(ErrorKind::IdNotFound, "category id a does not exist"),
+            Error::new(ErrorKind::DuplicatedId, "duplicated category id b")
+                .with_location(SourceLocation::with_position("./categories/b.json", 2, 9)),
+        ],
+    );
+
+    assert_eq!(2, error.errors().len());
+    assert_eq!(
+        "validation failed with 2 problem(s)\n    category id a does not exist\n    duplicated category id b (at ./categories/b.json:2:9)",
+        format!("{:#}", error)
+    );
+}
+
+#[test]
+fn diagnostic_keeps_details_and_full_message_of_error() {
+    let error = Error::new(ErrorKind::TypeChanged, "data type changed")
+        .with_details(ErrorDetails::DataTypeChange {
+            from: "string".to_string(),
+            to: "decimal".to_string(),
+        })
+        .with_source(std::io::Error::other("cause"));
+
+    let diagnostic = Diagnostic::from(error);
+
+    assert_eq!("data type changed: cause", diagnostic.message);
+    assert_eq!(
+        Some(ErrorDetails::DataTypeChange {
+            from: "string".to_string(),
+            to: "decimal".to_string(),
+        }),
+        diagnostic.details
+    );
+}
+
+#[test]
+fn keeps_the_definitions_library_message() {
+    let error = Error::from(cooplan_definitions_lib::error::Error::new(
+        cooplan_definitions_lib::error::ErrorKind::MissingId,
+        "category 'fruit' has no id",
+    ));
+
+    assert_eq!(ErrorKind::MissingId, error.kind());
+    assert_eq!(
+        "definitions library failed: category 'fruit' has no id",
+        error.to_string()
+    );
+}
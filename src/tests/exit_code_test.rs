@@ -0,0 +1,83 @@
+#[cfg(test)]
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::{Error, ErrorKind};
+use crate::exit_code::ExitCode;
+use crate::severity::Severity;
+
+fn warnings() -> Diagnostics {
+    let mut diagnostic = Diagnostic::new(ErrorKind::DeprecatedDataType, "deprecated");
+    diagnostic.severity = Severity::Warning;
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push(diagnostic);
+
+    diagnostics
+}
+
+#[test]
+fn warnings_only_fail_if_denied() {
+    assert_eq!(
+        ExitCode::Success,
+        ExitCode::from_run(&Ok(()), &warnings(), false)
+    );
+    assert_eq!(
+        ExitCode::DeniedWarnings,
+        ExitCode::from_run(&Ok(()), &warnings(), true)
+    );
+    assert_eq!(
+        ExitCode::Success,
+        ExitCode::from_run(&Ok(()), &Diagnostics::new(), true)
+    );
+}
+
+#[test]
+fn failed_validation_exits_with_errors() {
+    let result = Err(Error::new(ErrorKind::ValidationFailed, "validation failed"));
+
+    assert_eq!(
+        ExitCode::Errors,
+        ExitCode::from_run(&result, &warnings(), true)
+    );
+    assert_eq!(1, ExitCode::Errors.code());
+}
+
+#[test]
+fn failure_to_run_exits_with_internal_failure() {
+    let result = Err(Error::new(
+        ErrorKind::FailedToReadConfig,
+        "failed to read configuration file",
+    ));
+
+    assert_eq!(
+        ExitCode::InternalFailure,
+        ExitCode::from_run(&result, &Diagnostics::new(), false)
+    );
+    assert_eq!(3, ExitCode::InternalFailure.code());
+}
+
+#[test]
+fn invalid_definitions_exit_with_errors() {
+    for kind in [
+        ErrorKind::InvalidCategoryFile,
+        ErrorKind::ParentNotFound,
+        ErrorKind::CategoryCycle,
+        ErrorKind::InvalidCategoryTree,
+    ] {
+        let result = Err(Error::new(kind, "invalid definitions"));
+
+        assert_eq!(
+            ExitCode::Errors,
+            ExitCode::from_run(&result, &Diagnostics::new(), false)
+        );
+    }
+
+    let result = Err(Error::new(
+        ErrorKind::FailedToReadCategory,
+        "failed to read categories",
+    ));
+
+    assert_eq!(
+        ExitCode::InternalFailure,
+        ExitCode::from_run(&result, &Diagnostics::new(), false)
+    );
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+use crate::glob::glob_matches;
+
+#[test]
+fn patterns_without_separator_match_file_names() {
+    assert!(glob_matches("README.md", "README.md"));
+    assert!(glob_matches("README.md", "food/README.md"));
+    assert!(glob_matches("*.md", "food/fruit/notes.md"));
+    assert!(!glob_matches("*.md", "food/notes.txt"));
+}
+
+#[test]
+fn single_star_does_not_cross_directories() {
+    assert!(glob_matches("food/*.txt", "food/abcd.txt"));
+    assert!(!glob_matches("food/*.txt", "food/fruit/abcd.txt"));
+    assert!(glob_matches("food/?.txt", "food/a.txt"));
+}
+
+#[test]
+fn double_star_matches_any_directory() {
+    assert!(glob_matches("**/docs", "docs"));
+    assert!(glob_matches("**/docs", "food/fruit/docs"));
+    assert!(glob_matches("food/**", "food/fruit/pear.txt"));
+    assert!(!glob_matches("food/**", "drinks/water.txt"));
+}
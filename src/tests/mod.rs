@@ -1,2 +1,17 @@
+pub mod arguments_test;
 pub mod attributes;
 pub mod categories;
+pub mod ci_test;
+pub mod data_type_widenings_test;
+pub mod diagnostics_test;
+pub mod error_test;
+pub mod exit_code_test;
+pub mod glob_test;
+pub mod naming_convention_test;
+pub mod reporters;
+pub mod reserved_keywords_test;
+pub mod source_location_test;
+pub mod test_attributes;
+pub mod test_files;
+pub mod type_system_test;
+pub mod validation_registry_test;
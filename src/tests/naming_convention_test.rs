@@ -0,0 +1,82 @@
+#[cfg(test)]
+use crate::error::ErrorKind;
+use crate::naming_convention::{NamingConvention, NamingConventions, NamingPreset, NamingRules};
+
+#[test]
+fn parses_naming_conventions_from_json() {
+    let naming_conventions: NamingConventions = serde_json::from_str(
+        r#"{"attributes": {"preset": "snake_case", "max_length": 32, "charset": "a-z_"}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(None, naming_conventions.categories);
+    assert_eq!(
+        Some(NamingConvention {
+            preset: Some(NamingPreset::SnakeCase),
+            pattern: None,
+            max_length: Some(32),
+            charset: Some("a-z_".to_string()),
+        }),
+        naming_conventions.attributes
+    );
+}
+
+#[test]
+fn reports_every_violated_rule() {
+    let rules = NamingRules::try_new(&NamingConvention {
+        preset: Some(NamingPreset::SnakeCase),
+        pattern: None,
+        max_length: Some(10),
+        charset: Some("a-z_".to_string()),
+    })
+    .unwrap();
+
+    assert!(rules.violations("expiration").is_empty());
+    assert_eq!(
+        vec![
+            "does not follow snake_case".to_string(),
+            "contains characters other than 'a-z_'".to_string(),
+            "is longer than 10 characters".to_string(),
+        ],
+        rules.violations("Expiration Date")
+    );
+}
+
+#[test]
+fn matches_presets() {
+    let rules = |preset| {
+        NamingRules::try_new(&NamingConvention {
+            preset: Some(preset),
+            ..NamingConvention::default()
+        })
+        .unwrap()
+    };
+
+    assert!(rules(NamingPreset::SnakeCase)
+        .violations("expiration_date")
+        .is_empty());
+    assert!(!rules(NamingPreset::SnakeCase)
+        .violations("expiration__date")
+        .is_empty());
+    assert!(rules(NamingPreset::KebabCase)
+        .violations("expiration-date")
+        .is_empty());
+    assert!(rules(NamingPreset::CamelCase)
+        .violations("expirationDate")
+        .is_empty());
+    assert!(rules(NamingPreset::PascalCase)
+        .violations("ExpirationDate")
+        .is_empty());
+}
+
+#[test]
+fn error_if_pattern_is_not_valid() {
+    let error = NamingRules::try_new(&NamingConvention {
+        pattern: Some("([a-z]".to_string()),
+        ..NamingConvention::default()
+    })
+    .err()
+    .unwrap();
+
+    assert_eq!(ErrorKind::InvalidNamingConvention, error.kind());
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+use cooplan_definitions_lib::{attribute::Attribute, category::Category};
+
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::ErrorKind;
+use crate::reporters::json_reporter::JsonReporter;
+use crate::reporters::reporter::Reporter;
+
+#[test]
+fn reports_every_field_of_each_diagnostic() {
+    let attribute = Attribute {
+        id: "attribute-id".to_string(),
+        name: "color".to_string(),
+        data_type: "colour".to_string(),
+        unit: None,
+        optional: false,
+    };
+    let category = Category::new(
+        "category-id".to_string(),
+        "pear".to_string(),
+        true,
+        vec![attribute.clone()],
+    );
+
+    let mut diagnostic = Diagnostic::for_attribute(
+        ErrorKind::FailedDataTypeAttributeValidation,
+        "invalid data type",
+        &category.borrow(),
+        &attribute,
+    );
+    diagnostic.file_path = Some("./categories/pear.json".to_string());
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push(diagnostic);
+
+    let report = JsonReporter::new().report(&diagnostics).unwrap();
+    let json: serde_json::Value = serde_json::from_str(report.as_str()).unwrap();
+
+    assert_eq!(1, json.as_array().unwrap().len());
+    assert_eq!("FailedDataTypeAttributeValidation", json[0]["kind"]);
+    assert_eq!("invalid data type", json[0]["message"]);
+    assert_eq!("category-id", json[0]["category_id"]);
+    assert_eq!("pear", json[0]["category_name"]);
+    assert_eq!("attribute-id", json[0]["attribute_id"]);
+    assert_eq!("color", json[0]["attribute_name"]);
+    assert_eq!("./categories/pear.json", json[0]["file_path"]);
+}
@@ -0,0 +1,2 @@
+pub mod json_reporter_test;
+pub mod sarif_reporter_test;
@@ -0,0 +1,92 @@
+#[cfg(test)]
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::error::ErrorKind;
+use crate::reporters::reporter::Reporter;
+use crate::reporters::sarif_reporter::SarifReporter;
+
+#[test]
+fn reports_results_with_rules_and_locations() {
+    let mut located = Diagnostic::new(ErrorKind::LastCategoryNotSelectable, "not selectable");
+    located.file_path = Some("./categories/food.json".to_string());
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push(located);
+    diagnostics.push(Diagnostic::new(
+        ErrorKind::LastCategoryNotSelectable,
+        "not selectable either",
+    ));
+    diagnostics.push(Diagnostic::new(ErrorKind::IdNotTracked, "not tracked"));
+
+    let report = SarifReporter::new().report(&diagnostics).unwrap();
+    let sarif: serde_json::Value = serde_json::from_str(report.as_str()).unwrap();
+
+    assert_eq!("2.1.0", sarif["version"]);
+
+    let run = &sarif["runs"][0];
+    assert_eq!(2, run["tool"]["driver"]["rules"].as_array().unwrap().len());
+    assert_eq!(3, run["results"].as_array().unwrap().len());
+    assert_eq!("LastCategoryNotSelectable", run["results"][0]["ruleId"]);
+    assert_eq!(
+        "categories/food.json",
+        run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+    );
+    assert_eq!(
+        "SRCROOT",
+        run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uriBaseId"]
+    );
+    assert!(run["results"][1].get("locations").is_none());
+}
+
+#[test]
+fn reports_locations_relative_to_the_base_directory() {
+    let mut relative = Diagnostic::new(ErrorKind::IdNotTracked, "not tracked");
+    relative.file_path = Some("../defs/categories/food.json".to_string());
+
+    let mut absolute = Diagnostic::new(ErrorKind::IdNotTracked, "not tracked either");
+    absolute.file_path = Some("/repositories/defs/categories/food/fruit.json".to_string());
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push(relative);
+    diagnostics.push(absolute);
+
+    let current_directory = std::env::current_dir().unwrap();
+    let base_directory = current_directory.parent().unwrap().join("defs");
+
+    let report = SarifReporter::new()
+        .with_base_directory(base_directory.to_str().unwrap())
+        .report(&diagnostics)
+        .unwrap();
+    let sarif: serde_json::Value = serde_json::from_str(report.as_str()).unwrap();
+
+    let run = &sarif["runs"][0];
+    let base_uri = run["originalUriBaseIds"]["SRCROOT"]["uri"]
+        .as_str()
+        .unwrap();
+    assert!(base_uri.starts_with("file:///"));
+    assert!(base_uri.ends_with("/defs/"));
+    assert_eq!(
+        "categories/food.json",
+        run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+    );
+
+    let absolute_report = SarifReporter::new()
+        .with_base_directory("/repositories/defs/")
+        .report(&diagnostics)
+        .unwrap();
+    let absolute_sarif: serde_json::Value = serde_json::from_str(absolute_report.as_str()).unwrap();
+
+    let absolute_run = &absolute_sarif["runs"][0];
+    assert_eq!(
+        "file:///repositories/defs/",
+        absolute_run["originalUriBaseIds"]["SRCROOT"]["uri"]
+    );
+    assert_eq!(
+        "categories/food/fruit.json",
+        absolute_run["results"][1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+    );
+    assert_eq!(
+        "SRCROOT",
+        absolute_run["results"][1]["locations"][0]["physicalLocation"]["artifactLocation"]
+            ["uriBaseId"]
+    );
+}
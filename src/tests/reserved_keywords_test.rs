@@ -0,0 +1,52 @@
+#[cfg(test)]
+use crate::reserved_keywords::{KeywordMatching, ReservedKeyword, ReservedKeywords};
+
+fn keywords(matching: KeywordMatching) -> ReservedKeywords {
+    let keywords: Vec<ReservedKeyword> = serde_json::from_str(
+        r#"["Type", {"keyword": "created_at", "explanation": "set on creation"}]"#,
+    )
+    .unwrap();
+
+    ReservedKeywords::new(keywords, matching)
+}
+
+#[test]
+fn parses_keywords_with_and_without_explanation() {
+    let keywords: Vec<ReservedKeyword> = serde_json::from_str(
+        r#"["type", {"keyword": "version", "explanation": "tracked by the tool"}]"#,
+    )
+    .unwrap();
+
+    assert_eq!(ReservedKeyword::Keyword("type".to_string()), keywords[0]);
+    assert_eq!("version", keywords[1].keyword());
+    assert_eq!(Some("tracked by the tool"), keywords[1].explanation());
+}
+
+#[test]
+fn case_insensitive_matching_ignores_the_case_of_keywords_and_names() {
+    let keywords = keywords(KeywordMatching::CaseInsensitive);
+
+    assert_eq!(
+        Some("Type"),
+        keywords.find("type").map(|keyword| keyword.keyword())
+    );
+    assert!(keywords.find("TYPE").is_some());
+    assert!(keywords.find("createdAt").is_none());
+}
+
+#[test]
+fn exact_matching_requires_the_same_case() {
+    let keywords = keywords(KeywordMatching::Exact);
+
+    assert!(keywords.find("Type").is_some());
+    assert!(keywords.find("type").is_none());
+}
+
+#[test]
+fn normalized_matching_ignores_separators() {
+    let keywords = keywords(KeywordMatching::Normalized);
+
+    assert!(keywords.find("createdAt").is_some());
+    assert!(keywords.find("Created-At").is_some());
+    assert!(keywords.find("created").is_none());
+}
@@ -0,0 +1,23 @@
+#[cfg(test)]
+use crate::source_location::SourceLocation;
+
+#[test]
+fn extracts_position_from_parsing_error_messages() {
+    let location = SourceLocation::from_message(
+        "./categories/food.json",
+        "[./categories/food.json] expected `,` or `}` at line 3 column 5",
+    );
+
+    assert_eq!(Some(3), location.line);
+    assert_eq!(Some(5), location.column);
+    assert_eq!("./categories/food.json:3:5", location.to_string());
+}
+
+#[test]
+fn no_position_if_message_does_not_mention_it() {
+    let location = SourceLocation::from_message("./categories/food.json", "permission denied");
+
+    assert_eq!(None, location.line);
+    assert_eq!(None, location.column);
+    assert_eq!("./categories/food.json", location.to_string());
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use cooplan_definitions_lib::attribute::Attribute;
+use serde_json::Value;
+
+use crate::attributes::attribute_extension::{AttributeExtension, AttributeOption};
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::constraints::Constraints;
+use crate::attributes::option_tracker_io::OptionEntry;
+
+/// Mandatory attribute, named after its id, without unit.
+pub fn attribute(id: &str, data_type: &str) -> Attribute {
+    attribute_with_unit(id, data_type, None)
+}
+
+/// Mandatory attribute, named after its id, with the unit.
+pub fn attribute_with_unit(id: &str, data_type: &str, unit: Option<&str>) -> Attribute {
+    Attribute {
+        id: id.to_string(),
+        name: id.to_string(),
+        data_type: data_type.to_string(),
+        unit: unit.map(|unit| unit.to_string()),
+        optional: false,
+    }
+}
+
+/// Mandatory attribute, with the name, without unit.
+pub fn attribute_with_name(id: &str, name: &str, data_type: &str) -> Attribute {
+    Attribute {
+        name: name.to_string(),
+        ..attribute(id, data_type)
+    }
+}
+
+/// Entry, with every field tracked, of the attribute returned by `attribute` within the category "c1".
+pub fn entry(id: &str, data_type: &str) -> AttributeEntry {
+    entry_with_unit(id, data_type, Some(""))
+}
+
+/// Entry, with every field tracked, of an attribute with the unit within the category "c1".
+pub fn entry_with_unit(id: &str, data_type: &str, unit: Option<&str>) -> AttributeEntry {
+    AttributeEntry {
+        id: id.to_string(),
+        data_type: data_type.to_string(),
+        unit: unit.map(|unit| unit.to_string()),
+        name: Some(id.to_string()),
+        category_id: Some("c1".to_string()),
+        constraints: Some(Constraints::default()),
+        optional: Some(false),
+    }
+}
+
+/// Entries by their id, as read from the attribute id tracker.
+pub fn entries(entries: Vec<AttributeEntry>) -> HashMap<String, AttributeEntry> {
+    entries
+        .into_iter()
+        .map(|entry| (entry.id.clone(), entry))
+        .collect()
+}
+
+/// Option entry by its id, as read from the option id tracker.
+pub fn option_entry(id: &str, attribute_id: &str, name: &str) -> (String, OptionEntry) {
+    (
+        id.to_string(),
+        OptionEntry {
+            id: id.to_string(),
+            attribute_id: attribute_id.to_string(),
+            name: name.to_string(),
+        },
+    )
+}
+
+/// Constraints as written within an attribute extension, i.e. `{"min": 0}`.
+pub fn constraints(constraints: Value) -> Constraints {
+    serde_json::from_value::<Constraints>(constraints).unwrap()
+}
+
+/// Extension of the attribute with the id, with nothing but the constraints.
+pub fn extension(id: &str, constraints: Value) -> (String, AttributeExtension) {
+    (
+        id.to_string(),
+        AttributeExtension {
+            constraints: self::constraints(constraints),
+            ..Default::default()
+        },
+    )
+}
+
+/// Extension of the attribute with the id, with the default value and the constraints.
+pub fn extension_with_default(
+    id: &str,
+    default: Value,
+    constraints: Value,
+) -> (String, AttributeExtension) {
+    let (id, extension) = extension(id, constraints);
+
+    (
+        id,
+        AttributeExtension {
+            default: Some(default),
+            ..extension
+        },
+    )
+}
+
+/// Extension of the attribute with the id, with untracked options of the names.
+pub fn extension_with_options(id: &str, options: &[&str]) -> (String, AttributeExtension) {
+    (
+        id.to_string(),
+        AttributeExtension {
+            options: options
+                .iter()
+                .map(|name| AttributeOption {
+                    id: None,
+                    name: name.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        },
+    )
+}
@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+/// Creates an empty directory, unique to the test, within the system's temporary directory.
+pub fn create_test_directory(test_name: &str) -> PathBuf {
+    let directory = std::env::temp_dir()
+        .join("cooplan-definitions-validator-tests")
+        .join(format!("{}-{}", test_name, std::process::id()));
+
+    if directory.exists() {
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    std::fs::create_dir_all(&directory).unwrap();
+
+    directory
+}
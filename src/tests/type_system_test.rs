@@ -0,0 +1,73 @@
+#[cfg(test)]
+use crate::error::ErrorKind;
+use crate::type_system::{same_data_type, DataType, TypeSystem};
+
+fn type_system() -> TypeSystem {
+    TypeSystem::new(vec![
+        "string".to_string(),
+        "decimal".to_string(),
+        "enum".to_string(),
+        "list".to_string(),
+    ])
+}
+
+#[test]
+fn parses_parameterised_and_composite_data_types() {
+    assert_eq!(
+        DataType::Named {
+            name: "decimal".to_string(),
+            parameters: vec![
+                ("precision".to_string(), "10".to_string()),
+                ("scale".to_string(), "2".to_string())
+            ],
+        },
+        DataType::parse("decimal(precision=10, scale=2)").unwrap()
+    );
+    assert_eq!(
+        DataType::Enum {
+            options: vec!["red".to_string(), "dark green".to_string()],
+        },
+        DataType::parse("enum[red, dark green]").unwrap()
+    );
+    assert_eq!(
+        "list<string(max=64)>",
+        DataType::parse("list< string( max = 64 ) >")
+            .unwrap()
+            .to_string()
+    );
+    assert!(same_data_type("string(max=64)", "string( max=64 )"));
+}
+
+#[test]
+fn error_if_data_type_does_not_follow_the_grammar() {
+    for data_type in [
+        "",
+        "enum[]",
+        "enum[red,]",
+        "list<string",
+        "string(max)",
+        "string)",
+    ] {
+        assert_eq!(
+            ErrorKind::InvalidDataType,
+            DataType::parse(data_type).unwrap_err().kind(),
+            "{}",
+            data_type
+        );
+    }
+}
+
+#[test]
+fn reports_invalid_data_types_and_parameters() {
+    let type_system = type_system();
+    let violations = |data_type: &str| type_system.violations(&DataType::parse(data_type).unwrap());
+
+    assert!(violations("list<enum[red,green]>").is_empty());
+    assert!(violations("decimal(precision=10,scale=2)").is_empty());
+    assert_eq!(1, violations("list<date>").len());
+    assert_eq!(1, violations("string(precision=10)").len());
+    assert_eq!(1, violations("string(max=-1)").len());
+    assert_eq!(1, violations("string(max=0)").len());
+    assert_eq!(1, violations("decimal(precision=2,scale=3)").len());
+    assert_eq!(1, violations("enum[red,red]").len());
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::categories::category_id_tracker_memory_io::CategoryIdTrackerMemoryIO;
+use crate::categories::validations::selectable_as_last_validation::SelectableAsLastValidation;
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+use crate::error::{Error, ErrorKind};
+use crate::severity::Severity;
+use crate::validation_registry::{
+    ValidationContext, ValidationRegistry, CATEGORY_ID_TRACKING, SELECTABLE_AS_LAST,
+};
+
+fn validate_categories(
+    config: &Config,
+    root_categories: &[Rc<RefCell<Category>>],
+) -> Result<Diagnostics, Error> {
+    let attribute_entries = HashMap::new();
+    let context = ValidationContext {
+        config,
+        category_id_tracker_io: Rc::new(CategoryIdTrackerMemoryIO::new(HashMap::new())),
+        attribute_entries: &attribute_entries,
+        attribute_extensions: &HashMap::new(),
+        option_entries: &HashMap::new(),
+        categories_path: &HashMap::new(),
+        categories_root: "",
+    };
+
+    let mut registry = ValidationRegistry::empty();
+    registry.register_category_validation(
+        SELECTABLE_AS_LAST,
+        Severity::Error,
+        Box::new(|_| Box::new(SelectableAsLastValidation::new())),
+    );
+
+    match registry.create_category_validations(&context) {
+        Ok(validations) => {
+            let mut diagnostics = Diagnostics::new();
+
+            for validation in validations {
+                validation
+                    .validate(root_categories, &mut diagnostics)
+                    .unwrap();
+            }
+
+            Ok(diagnostics)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+fn unselectable_category() -> Vec<Rc<RefCell<Category>>> {
+    vec![Category::new(
+        "ABCD".to_string(),
+        "ABCD".to_string(),
+        false,
+        Vec::new(),
+    )]
+}
+
+#[test]
+fn reports_errors_by_default() {
+    let config = Config::new(Vec::new(), Vec::new());
+
+    let diagnostics = validate_categories(&config, unselectable_category().as_slice()).unwrap();
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(Severity::Error, diagnostics.as_slice()[0].severity());
+}
+
+#[test]
+fn configured_severity_is_set_to_every_diagnostic() {
+    let mut config = Config::new(Vec::new(), Vec::new());
+    config.set_validation_severity(SELECTABLE_AS_LAST, Severity::Warning);
+
+    let diagnostics = validate_categories(&config, unselectable_category().as_slice()).unwrap();
+
+    assert_eq!(1, diagnostics.count(Severity::Warning));
+    assert_eq!(0, diagnostics.count(Severity::Error));
+}
+
+#[test]
+fn validations_turned_off_are_not_created() {
+    let mut config = Config::new(Vec::new(), Vec::new());
+    config.set_validation_severity(SELECTABLE_AS_LAST, Severity::Off);
+
+    let diagnostics = validate_categories(&config, unselectable_category().as_slice()).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn error_if_configuration_refers_to_unknown_validation() {
+    let mut config = Config::new(Vec::new(), Vec::new());
+    config.set_validation_severity("no_such_validation", Severity::Warning);
+
+    match validate_categories(&config, unselectable_category().as_slice()) {
+        Ok(_) => panic!("expected unknown validation error"),
+        Err(error) => assert_eq!(ErrorKind::UnknownValidation, error.kind()),
+    }
+}
+
+#[test]
+fn severity_falls_back_to_registration() {
+    let mut config = Config::new(Vec::new(), Vec::new());
+    config.set_validation_severity(SELECTABLE_AS_LAST, Severity::Warning);
+    let registry = ValidationRegistry::default();
+
+    assert_eq!(
+        Some(Severity::Warning),
+        registry.severity(SELECTABLE_AS_LAST, &config)
+    );
+    assert_eq!(
+        Some(Severity::Error),
+        registry.severity(CATEGORY_ID_TRACKING, &config)
+    );
+    assert_eq!(None, registry.severity("no_such_validation", &config));
+}
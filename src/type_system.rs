@@ -0,0 +1,391 @@
+use std::fmt;
+
+use crate::error::{Error, ErrorKind};
+
+pub const ENUM: &str = "enum";
+pub const LIST: &str = "list";
+/// Data types whose values are numbers, the only ones that accept units.
+pub const NUMERIC_DATA_TYPES: [&str; 2] = ["integer", "decimal"];
+
+/// Parsed data type of an attribute, following the grammar:
+///
+/// * `string`, `date` - named data type.
+/// * `string(max=64)`, `decimal(precision=10,scale=2)` - named data type with parameters.
+/// * `enum[red,green]` - one of the listed options.
+/// * `enum` - one of the options declared alongside the attribute, parsed as a named data type.
+/// * `list<string>` - list of items of the data type within the brackets.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataType {
+    Named {
+        name: String,
+        parameters: Vec<(String, String)>,
+    },
+    Enum {
+        options: Vec<String>,
+    },
+    List {
+        item: Box<DataType>,
+    },
+}
+
+impl DataType {
+    /// Error kinds:
+    ///
+    /// * `InvalidDataType` - if the data type does not follow the grammar.
+    pub fn parse(data_type: &str) -> Result<DataType, Error> {
+        let mut parser = Parser {
+            source: data_type,
+            chars: data_type.chars().collect(),
+            position: 0,
+        };
+
+        match parser.parse_data_type() {
+            Ok(parsed) => {
+                parser.skip_whitespace();
+
+                match parser.peek() {
+                    Some(character) => {
+                        Err(parser.error(format!("unexpected '{}'", character).as_str()))
+                    }
+                    None => Ok(parsed),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Name of the data type without its parameters, options or item, e.g. `string` for `string(max=64)`.
+    pub fn name(&self) -> &str {
+        match self {
+            DataType::Named { name, .. } => name.as_str(),
+            DataType::Enum { .. } => ENUM,
+            DataType::List { .. } => LIST,
+        }
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        match self {
+            DataType::Named { parameters, .. } => parameters
+                .iter()
+                .find(|(parameter_name, _)| parameter_name == name)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DataType {
+    /// Canonical form of the data type, without whitespace.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Named { name, parameters } => {
+                if parameters.is_empty() {
+                    return write!(f, "{}", name);
+                }
+
+                let parameters: Vec<String> = parameters
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, value))
+                    .collect();
+
+                write!(f, "{}({})", name, parameters.join(","))
+            }
+            DataType::Enum { options } => write!(f, "{}[{}]", ENUM, options.join(",")),
+            DataType::List { item } => write!(f, "{}<{}>", LIST, item),
+        }
+    }
+}
+
+/// Name of the data type, without parameters, options or item, or the data type as it is if it cannot be parsed.
+pub fn data_type_name(data_type: &str) -> String {
+    match DataType::parse(data_type) {
+        Ok(parsed) => parsed.name().to_string(),
+        Err(_) => data_type.to_string(),
+    }
+}
+
+/// Whether both data types are the same once parsed, e.g. `string(max=64)` and `string( max = 64 )`.
+pub fn same_data_type(first: &str, second: &str) -> bool {
+    match (DataType::parse(first), DataType::parse(second)) {
+        (Ok(first), Ok(second)) => first == second,
+        _ => first == second,
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn parse_data_type(&mut self) -> Result<DataType, Error> {
+        self.skip_whitespace();
+
+        let name = self.read_identifier();
+
+        if name.is_empty() {
+            return Err(self.error("expected a data type's name"));
+        }
+
+        self.skip_whitespace();
+
+        match name.as_str() {
+            ENUM if self.peek() == Some('[') => match self.expect('[') {
+                Ok(_) => match self.read_list(']') {
+                    Ok(options) => Ok(DataType::Enum { options }),
+                    Err(error) => Err(error),
+                },
+                Err(error) => Err(error),
+            },
+            LIST => match self.expect('<') {
+                Ok(_) => match self.parse_data_type() {
+                    Ok(item) => {
+                        self.skip_whitespace();
+
+                        match self.expect('>') {
+                            Ok(_) => Ok(DataType::List {
+                                item: Box::new(item),
+                            }),
+                            Err(error) => Err(error),
+                        }
+                    }
+                    Err(error) => Err(error),
+                },
+                Err(error) => Err(error),
+            },
+            _ => {
+                if self.peek() != Some('(') {
+                    return Ok(DataType::Named {
+                        name,
+                        parameters: Vec::new(),
+                    });
+                }
+
+                self.position += 1;
+
+                match self.read_list(')') {
+                    Ok(values) => {
+                        let mut parameters: Vec<(String, String)> = Vec::new();
+
+                        for value in values {
+                            match value.split_once('=') {
+                                Some((parameter_name, parameter_value))
+                                    if !parameter_name.trim().is_empty()
+                                        && !parameter_value.trim().is_empty() =>
+                                {
+                                    parameters.push((
+                                        parameter_name.trim().to_string(),
+                                        parameter_value.trim().to_string(),
+                                    ))
+                                }
+                                _ => {
+                                    return Err(Error::new(
+                                        ErrorKind::InvalidDataType,
+                                        format!(
+                                            "'{}' has the parameter '{}', which is not written as 'name=value'",
+                                            self.source, value
+                                        )
+                                        .as_str(),
+                                    ))
+                                }
+                            }
+                        }
+
+                        Ok(DataType::Named { name, parameters })
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    }
+
+    /// Reads the comma separated values until the closing character, which is consumed.
+    fn read_list(&mut self, closing: char) -> Result<Vec<String>, Error> {
+        let mut values: Vec<String> = Vec::new();
+        let mut value = String::new();
+
+        loop {
+            match self.peek() {
+                Some(character) if character == ',' || character == closing => {
+                    let trimmed_value = value.trim();
+
+                    if trimmed_value.is_empty() {
+                        return Err(self.error("expected a value"));
+                    }
+
+                    values.push(trimmed_value.to_string());
+                    value.clear();
+                    self.position += 1;
+
+                    if character == closing {
+                        return Ok(values);
+                    }
+                }
+                Some(character) => {
+                    value.push(character);
+                    self.position += 1;
+                }
+                None => {
+                    return Err(self.error(format!("expected '{}'", closing).as_str()));
+                }
+            }
+        }
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let mut identifier = String::new();
+
+        while let Some(character) = self.peek() {
+            if !character.is_alphanumeric() && character != '_' {
+                break;
+            }
+
+            identifier.push(character);
+            self.position += 1;
+        }
+
+        identifier
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+
+            return Ok(());
+        }
+
+        Err(self.error(format!("expected '{}'", expected).as_str()))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|character| character.is_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidDataType,
+            format!(
+                "'{}' is not a valid data type, {} at position {}",
+                self.source, message, self.position
+            )
+            .as_str(),
+        )
+    }
+}
+
+/// Data types attributes can use, alongside the parameters each of them accepts:
+///
+/// * `string` - `max`, its maximum length.
+/// * `decimal` - `precision`, its number of digits, and `scale`, its number of digits after the decimal point.
+///
+/// `enum` and `list` are only valid if they are listed, like any other data type.
+pub struct TypeSystem {
+    valid_data_types: Vec<String>,
+}
+
+impl TypeSystem {
+    pub fn new(valid_data_types: Vec<String>) -> TypeSystem {
+        TypeSystem { valid_data_types }
+    }
+
+    /// Problems of the data type, empty if it is valid.
+    pub fn violations(&self, data_type: &DataType) -> Vec<String> {
+        let mut violations: Vec<String> = Vec::new();
+
+        if !self
+            .valid_data_types
+            .iter()
+            .any(|valid_data_type| valid_data_type == data_type.name())
+        {
+            violations.push(format!("'{}' is not a valid data type", data_type.name()));
+        }
+
+        match data_type {
+            DataType::Named { name, parameters } => {
+                let accepted_parameters = accepted_parameters(name.as_str());
+
+                for (index, (parameter_name, value)) in parameters.iter().enumerate() {
+                    if !accepted_parameters.contains(&parameter_name.as_str()) {
+                        violations.push(format!(
+                            "'{}' does not accept the parameter '{}'",
+                            name, parameter_name
+                        ));
+                    } else if parameters[..index]
+                        .iter()
+                        .any(|(previous_name, _)| previous_name == parameter_name)
+                    {
+                        violations.push(format!(
+                            "the parameter '{}' is set more than once",
+                            parameter_name
+                        ));
+                    } else if value.parse::<u32>().is_err() {
+                        violations.push(format!(
+                            "the parameter '{}' must be a non-negative integer, not '{}'",
+                            parameter_name, value
+                        ));
+                    }
+                }
+
+                violations.extend(parameter_violations(data_type));
+            }
+            DataType::Enum { options } => {
+                for (index, option) in options.iter().enumerate() {
+                    if options[..index].contains(option) {
+                        violations
+                            .push(format!("the option '{}' is listed more than once", option));
+                    }
+                }
+            }
+            DataType::List { item } => violations.extend(self.violations(item)),
+        }
+
+        violations
+    }
+}
+
+fn accepted_parameters(name: &str) -> &'static [&'static str] {
+    match name {
+        "string" => &["max"],
+        "decimal" => &["precision", "scale"],
+        _ => &[],
+    }
+}
+
+/// Problems between the values of the data type's parameters.
+fn parameter_violations(data_type: &DataType) -> Vec<String> {
+    let mut violations: Vec<String> = Vec::new();
+    let value = |name: &str| {
+        data_type
+            .parameter(name)
+            .and_then(|value| value.parse::<u32>().ok())
+    };
+
+    if value("max") == Some(0) {
+        violations.push("the parameter 'max' must be greater than 0".to_string());
+    }
+
+    if value("precision") == Some(0) {
+        violations.push("the parameter 'precision' must be greater than 0".to_string());
+    }
+
+    if let (Some(precision), Some(scale)) = (value("precision"), value("scale")) {
+        if scale > precision {
+            violations.push(format!(
+                "the parameter 'scale' ({}) cannot be greater than 'precision' ({})",
+                scale, precision
+            ));
+        }
+    }
+
+    violations
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::type_system::NUMERIC_DATA_TYPES;
+
+/// Units of a data type, which can be used either as they are or preceded by any of the prefixes.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct DataTypeUnits {
+    pub units: Vec<String>,
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+}
+
+/// Units attributes can use, per data type, as set within the configuration:
+///
+/// ```json
+/// "unit_catalog": {
+///     "decimal": { "units": ["m", "g", "l"], "prefixes": ["k", "c", "m"] },
+///     "integer": { "units": ["g"] }
+/// }
+/// ```
+///
+/// Only the numeric data types, `integer` and `decimal`, accept units, as long as they are within the catalog.
+/// Any other data type within the catalog does not accept units either.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct UnitCatalog {
+    data_types: HashMap<String, DataTypeUnits>,
+}
+
+impl UnitCatalog {
+    pub fn new(data_types: HashMap<String, DataTypeUnits>) -> UnitCatalog {
+        UnitCatalog { data_types }
+    }
+
+    /// Whether the data type accepts units at all, i.e. it is numeric and within the catalog.
+    pub fn accepts_units(&self, data_type: &str) -> bool {
+        NUMERIC_DATA_TYPES.contains(&data_type) && self.data_types.contains_key(data_type)
+    }
+
+    /// Whether the unit, optionally preceded by a prefix, belongs to the data type's units, given the data type
+    /// accepts units.
+    pub fn contains(&self, data_type: &str, unit: &str) -> bool {
+        if !self.accepts_units(data_type) {
+            return false;
+        }
+
+        match self.data_types.get(data_type) {
+            Some(data_type_units) => data_type_units.units.iter().any(|catalog_unit| {
+                unit == catalog_unit
+                    || data_type_units
+                        .prefixes
+                        .iter()
+                        .any(|prefix| unit == format!("{}{}", prefix, catalog_unit))
+            }),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,532 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cooplan_definitions_lib::category::Category;
+
+use crate::attributes::attribute_extension::AttributeExtension;
+use crate::attributes::attribute_tracker_io::AttributeEntry;
+use crate::attributes::option_tracker_io::OptionEntry;
+use crate::attributes::validations::attribute_category_constant_validation::AttributeCategoryConstantValidation;
+use crate::attributes::validations::attribute_name_constant_validation::AttributeNameConstantValidation;
+use crate::attributes::validations::attribute_naming_convention_validation::AttributeNamingConventionValidation;
+use crate::attributes::validations::constraint_tightening_validation::ConstraintTighteningValidation;
+use crate::attributes::validations::constraints_validation::ConstraintsValidation;
+use crate::attributes::validations::data_type_constant_validation::DataTypeConstantValidation;
+use crate::attributes::validations::data_type_validation::DataTypeValidation;
+use crate::attributes::validations::default_value_validation::DefaultValueValidation;
+use crate::attributes::validations::deprecated_data_type_validation::DeprecatedDataTypeValidation;
+use crate::attributes::validations::duplicate_attribute_name_validation::DuplicateAttributeNameValidation;
+use crate::attributes::validations::enum_options_validation::EnumOptionsValidation;
+use crate::attributes::validations::option_id_tracking_validation::OptionIdTrackingValidation;
+use crate::attributes::validations::reserved_attribute_names_validation::ReservedAttributeNamesValidation;
+use crate::attributes::validations::unit_constant_validation::UnitConstantValidation;
+use crate::attributes::validations::unit_validation::UnitValidation;
+use crate::attributes::validations::validation::Validation as AttributeValidation;
+use crate::categories::category_id_tracker_io::CategoryIdTrackerIO;
+use crate::categories::validations::category_files_validation::CategoryFilesValidation;
+use crate::categories::validations::category_naming_convention_validation::CategoryNamingConventionValidation;
+use crate::categories::validations::directory_layout_validation::DirectoryLayoutValidation;
+use crate::categories::validations::duplicate_category_name_validation::DuplicateCategoryNameValidation;
+use crate::categories::validations::id_tracking_validation::IdTrackingValidation;
+use crate::categories::validations::reserved_category_names_validation::ReservedCategoryNamesValidation;
+use crate::categories::validations::selectable_as_last_validation::SelectableAsLastValidation;
+use crate::categories::validations::validation::Validation as CategoryValidation;
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+use crate::error::{Error, ErrorKind};
+use crate::severity::Severity;
+
+pub const CATEGORY_ID_TRACKING: &str = "category_id_tracking";
+pub const SELECTABLE_AS_LAST: &str = "selectable_as_last";
+pub const DUPLICATE_CATEGORY_NAME: &str = "duplicate_category_name";
+pub const DIRECTORY_LAYOUT: &str = "directory_layout";
+pub const CATEGORY_FILES: &str = "category_files";
+pub const CATEGORY_NAMING_CONVENTION: &str = "category_naming_convention";
+pub const RESERVED_CATEGORY_NAMES: &str = "reserved_category_names";
+pub const ATTRIBUTE_ID_TRACKING: &str = "attribute_id_tracking";
+pub const DATA_TYPE_CONSTANT: &str = "data_type_constant";
+pub const RESERVED_ATTRIBUTE_NAMES: &str = "reserved_attribute_names";
+pub const DATA_TYPE: &str = "data_type";
+pub const DEPRECATED_DATA_TYPE: &str = "deprecated_data_type";
+pub const DUPLICATE_ATTRIBUTE_NAME: &str = "duplicate_attribute_name";
+pub const ATTRIBUTE_NAMING_CONVENTION: &str = "attribute_naming_convention";
+pub const UNIT: &str = "unit";
+pub const UNIT_CONSTANT: &str = "unit_constant";
+pub const ATTRIBUTE_NAME_CONSTANT: &str = "attribute_name_constant";
+pub const ATTRIBUTE_CATEGORY_CONSTANT: &str = "attribute_category_constant";
+pub const ENUM_OPTIONS: &str = "enum_options";
+pub const OPTION_ID_TRACKING: &str = "option_id_tracking";
+pub const CONSTRAINTS: &str = "constraints";
+pub const CONSTRAINT_TIGHTENING: &str = "constraint_tightening";
+pub const DEFAULT_VALUE: &str = "default_value";
+
+/// State the validations are created from.
+pub struct ValidationContext<'a> {
+    pub config: &'a Config,
+    pub category_id_tracker_io: Rc<dyn CategoryIdTrackerIO>,
+    pub attribute_entries: &'a HashMap<String, AttributeEntry>,
+    /// Extension of each attribute that has any, with the attribute's id as key.
+    pub attribute_extensions: &'a HashMap<String, AttributeExtension>,
+    pub option_entries: &'a HashMap<String, OptionEntry>,
+    /// Path of each category's file, with the category's id as key.
+    pub categories_path: &'a HashMap<String, String>,
+    /// Root directory of the categories.
+    pub categories_root: &'a str,
+}
+
+pub type CategoryValidationFactory = Box<dyn Fn(&ValidationContext) -> Box<dyn CategoryValidation>>;
+pub type AttributeValidationFactory =
+    Box<dyn Fn(&ValidationContext) -> Box<dyn AttributeValidation>>;
+
+struct Registration<T> {
+    name: String,
+    default_severity: Severity,
+    factory: T,
+}
+
+/// Named category and attribute validations, which the configuration can enable, disable or set the
+/// severity of through its `validations` section:
+///
+/// ```json
+/// "validations": {
+///     "selectable_as_last": "warning",
+///     "reserved_attribute_names": "off"
+/// }
+/// ```
+///
+/// Validations are run in the order they have been registered.
+pub struct ValidationRegistry {
+    category_validations: Vec<Registration<CategoryValidationFactory>>,
+    attribute_validations: Vec<Registration<AttributeValidationFactory>>,
+}
+
+impl ValidationRegistry {
+    /// Creates a registry without any validation.
+    pub fn empty() -> ValidationRegistry {
+        ValidationRegistry {
+            category_validations: Vec::new(),
+            attribute_validations: Vec::new(),
+        }
+    }
+
+    /// Registers a category validation, replacing any validation registered with the same name.
+    pub fn register_category_validation(
+        &mut self,
+        name: &str,
+        default_severity: Severity,
+        factory: CategoryValidationFactory,
+    ) {
+        self.category_validations
+            .retain(|registration| registration.name != name);
+        self.category_validations.push(Registration {
+            name: name.to_string(),
+            default_severity,
+            factory,
+        });
+    }
+
+    /// Registers an attribute validation, replacing any validation registered with the same name.
+    pub fn register_attribute_validation(
+        &mut self,
+        name: &str,
+        default_severity: Severity,
+        factory: AttributeValidationFactory,
+    ) {
+        self.attribute_validations
+            .retain(|registration| registration.name != name);
+        self.attribute_validations.push(Registration {
+            name: name.to_string(),
+            default_severity,
+            factory,
+        });
+    }
+
+    /// Names of the registered validations, categories' first.
+    pub fn names(&self) -> Vec<&str> {
+        self.category_validations
+            .iter()
+            .map(|registration| registration.name.as_str())
+            .chain(
+                self.attribute_validations
+                    .iter()
+                    .map(|registration| registration.name.as_str()),
+            )
+            .collect()
+    }
+
+    /// Severity of the validation, as set by the configuration or, if not set, by its registration.
+    pub fn severity(&self, name: &str, config: &Config) -> Option<Severity> {
+        let default_severity = self
+            .category_validations
+            .iter()
+            .find(|registration| registration.name == name)
+            .map(|registration| registration.default_severity)
+            .or_else(|| {
+                self.attribute_validations
+                    .iter()
+                    .find(|registration| registration.name == name)
+                    .map(|registration| registration.default_severity)
+            });
+
+        default_severity
+            .map(|default_severity| config.validation_severity(name).unwrap_or(default_severity))
+    }
+
+    /// Creates the category validations that are not turned off.
+    ///
+    /// Error kinds:
+    ///
+    /// * `UnknownValidation` - if the configuration refers to a validation that is not registered.
+    pub fn create_category_validations(
+        &self,
+        context: &ValidationContext,
+    ) -> Result<Vec<Box<dyn CategoryValidation>>, Error> {
+        match self.ensure_known_validations(context.config) {
+            Ok(_) => (),
+            Err(error) => return Err(error),
+        }
+
+        let mut validations: Vec<Box<dyn CategoryValidation>> = Vec::new();
+
+        for registration in self.category_validations.as_slice() {
+            let severity = context
+                .config
+                .validation_severity(registration.name.as_str())
+                .unwrap_or(registration.default_severity);
+
+            if severity != Severity::Off {
+                validations.push(Box::new(CategoryValidationWithSeverity {
+                    validation: (registration.factory)(context),
+                    severity,
+                }));
+            }
+        }
+
+        Ok(validations)
+    }
+
+    /// Creates the attribute validations that are not turned off.
+    ///
+    /// Error kinds:
+    ///
+    /// * `UnknownValidation` - if the configuration refers to a validation that is not registered.
+    pub fn create_attribute_validations(
+        &self,
+        context: &ValidationContext,
+    ) -> Result<Vec<Rc<RefCell<dyn AttributeValidation>>>, Error> {
+        match self.ensure_known_validations(context.config) {
+            Ok(_) => (),
+            Err(error) => return Err(error),
+        }
+
+        let mut validations: Vec<Rc<RefCell<dyn AttributeValidation>>> = Vec::new();
+
+        for registration in self.attribute_validations.as_slice() {
+            let severity = context
+                .config
+                .validation_severity(registration.name.as_str())
+                .unwrap_or(registration.default_severity);
+
+            if severity != Severity::Off {
+                validations.push(Rc::new(RefCell::new(AttributeValidationWithSeverity {
+                    validation: (registration.factory)(context),
+                    severity,
+                })));
+            }
+        }
+
+        Ok(validations)
+    }
+
+    fn ensure_known_validations(&self, config: &Config) -> Result<(), Error> {
+        let names = self.names();
+        let mut unknown_names: Vec<&str> = config
+            .validations()
+            .keys()
+            .map(|name| name.as_str())
+            .filter(|name| !names.contains(name))
+            .collect();
+
+        if unknown_names.is_empty() {
+            return Ok(());
+        }
+
+        unknown_names.sort();
+
+        Err(Error::new(
+            ErrorKind::UnknownValidation,
+            format!(
+                "configuration refers to unknown validation(s): {}",
+                unknown_names.join(", ")
+            )
+            .as_str(),
+        ))
+    }
+}
+
+impl Default for ValidationRegistry {
+    /// Registry of the built-in validations, all of them reporting errors except the directory layout and
+    /// the deprecated data type validations, which report warnings.
+    fn default() -> Self {
+        let mut registry = ValidationRegistry::empty();
+
+        registry.register_category_validation(
+            CATEGORY_ID_TRACKING,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(IdTrackingValidation::new(Rc::clone(
+                    &context.category_id_tracker_io,
+                )))
+            }),
+        );
+        registry.register_category_validation(
+            SELECTABLE_AS_LAST,
+            Severity::Error,
+            Box::new(|_| Box::new(SelectableAsLastValidation::new())),
+        );
+        registry.register_category_validation(
+            DUPLICATE_CATEGORY_NAME,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(DuplicateCategoryNameValidation::new(
+                    context.categories_path.clone(),
+                ))
+            }),
+        );
+        registry.register_category_validation(
+            DIRECTORY_LAYOUT,
+            Severity::Warning,
+            Box::new(|context| {
+                Box::new(DirectoryLayoutValidation::new(
+                    context.categories_root,
+                    context.categories_path.clone(),
+                ))
+            }),
+        );
+        registry.register_category_validation(
+            CATEGORY_FILES,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(CategoryFilesValidation::new(
+                    context.categories_root,
+                    context.config.allowed_files(),
+                ))
+            }),
+        );
+        registry.register_category_validation(
+            CATEGORY_NAMING_CONVENTION,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(CategoryNamingConventionValidation::new(
+                    context.config.category_naming_convention(),
+                ))
+            }),
+        );
+        registry.register_category_validation(
+            RESERVED_CATEGORY_NAMES,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(ReservedCategoryNamesValidation::new(
+                    context.config.reserved_category_keywords(),
+                ))
+            }),
+        );
+
+        registry.register_attribute_validation(
+            ATTRIBUTE_ID_TRACKING,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(crate::attributes::validations::id_tracking_validation::IdTrackingValidation::new(
+                    context.attribute_entries,
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            DATA_TYPE_CONSTANT,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(DataTypeConstantValidation::new(
+                    context.attribute_entries,
+                    context.config.data_type_widenings(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            RESERVED_ATTRIBUTE_NAMES,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(ReservedAttributeNamesValidation::new(
+                    context.config.reserved_attribute_keywords(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            DUPLICATE_ATTRIBUTE_NAME,
+            Severity::Error,
+            Box::new(|_| Box::new(DuplicateAttributeNameValidation::new())),
+        );
+        registry.register_attribute_validation(
+            ATTRIBUTE_NAMING_CONVENTION,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(AttributeNamingConventionValidation::new(
+                    context.config.attribute_naming_convention(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            UNIT,
+            Severity::Error,
+            Box::new(|context| Box::new(UnitValidation::new(context.config.unit_catalog()))),
+        );
+        registry.register_attribute_validation(
+            UNIT_CONSTANT,
+            Severity::Error,
+            Box::new(|context| Box::new(UnitConstantValidation::new(context.attribute_entries))),
+        );
+        registry.register_attribute_validation(
+            ATTRIBUTE_NAME_CONSTANT,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(AttributeNameConstantValidation::new(
+                    context.attribute_entries,
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            ATTRIBUTE_CATEGORY_CONSTANT,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(AttributeCategoryConstantValidation::new(
+                    context.attribute_entries,
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            ENUM_OPTIONS,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(EnumOptionsValidation::new(
+                    context.attribute_extensions.clone(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            OPTION_ID_TRACKING,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(OptionIdTrackingValidation::new(
+                    context.option_entries,
+                    context.attribute_extensions.clone(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            CONSTRAINTS,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(ConstraintsValidation::new(
+                    context.attribute_extensions.clone(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            CONSTRAINT_TIGHTENING,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(ConstraintTighteningValidation::new(
+                    context.attribute_entries,
+                    context.attribute_extensions.clone(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            DEFAULT_VALUE,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(DefaultValueValidation::new(
+                    context.attribute_extensions.clone(),
+                    context.config.data_type_formats(),
+                ))
+            }),
+        );
+        registry.register_attribute_validation(
+            DATA_TYPE,
+            Severity::Error,
+            Box::new(|context| {
+                Box::new(DataTypeValidation::new(context.config.valid_data_types()))
+            }),
+        );
+
+        registry.register_attribute_validation(
+            DEPRECATED_DATA_TYPE,
+            Severity::Warning,
+            Box::new(|context| {
+                Box::new(DeprecatedDataTypeValidation::new(
+                    context.config.deprecated_data_types(),
+                ))
+            }),
+        );
+
+        registry
+    }
+}
+
+/// Sets the configured severity to every problem found by the wrapped validation.
+struct CategoryValidationWithSeverity {
+    validation: Box<dyn CategoryValidation>,
+    severity: Severity,
+}
+
+impl CategoryValidation for CategoryValidationWithSeverity {
+    fn validate(
+        &self,
+        root_categories: &[Rc<RefCell<Category>>],
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let mut found_diagnostics = Diagnostics::new();
+        let result = self
+            .validation
+            .validate(root_categories, &mut found_diagnostics);
+
+        found_diagnostics.set_severity(self.severity);
+        diagnostics.append(found_diagnostics);
+
+        result
+    }
+}
+
+/// Sets the configured severity to every problem found by the wrapped validation.
+struct AttributeValidationWithSeverity {
+    validation: Box<dyn AttributeValidation>,
+    severity: Severity,
+}
+
+impl AttributeValidation for AttributeValidationWithSeverity {
+    fn partially_validate(
+        &mut self,
+        category: &Category,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), Error> {
+        let mut found_diagnostics = Diagnostics::new();
+        let result = self
+            .validation
+            .partially_validate(category, &mut found_diagnostics);
+
+        found_diagnostics.set_severity(self.severity);
+        diagnostics.append(found_diagnostics);
+
+        result
+    }
+
+    fn complete(&mut self, diagnostics: &mut Diagnostics) -> Result<(), Error> {
+        let mut found_diagnostics = Diagnostics::new();
+        let result = self.validation.complete(&mut found_diagnostics);
+
+        found_diagnostics.set_severity(self.severity);
+        diagnostics.append(found_diagnostics);
+
+        result
+    }
+}